@@ -0,0 +1,65 @@
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Yields `init`, then the accumulator folded with `f` after each item of `stream`.
+///
+/// This yields one more item than `stream` does: for `N` inputs, `N + 1` accumulators.
+pub fn accumulate<S, Acc, F>(stream: S, init: Acc, f: F) -> impl Stream<Item = Acc>
+where
+    S: Stream,
+    Acc: Clone,
+    F: FnMut(&Acc, S::Item) -> Acc,
+{
+    Accumulate {
+        stream,
+        acc: init,
+        f,
+        started: false,
+    }
+}
+
+#[pin_project::pin_project]
+struct Accumulate<S, Acc, F> {
+    #[pin]
+    stream: S,
+    acc: Acc,
+    f: F,
+    started: bool,
+}
+
+impl<S, Acc, F> Stream for Accumulate<S, Acc, F>
+where
+    S: Stream,
+    Acc: Clone,
+    F: FnMut(&Acc, S::Item) -> Acc,
+{
+    type Item = Acc;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let me = self.project();
+
+        if !*me.started {
+            *me.started = true;
+            return Poll::Ready(Some(me.acc.clone()));
+        }
+
+        let Some(item) = ready!(me.stream.poll_next(cx)) else {
+            return Poll::Ready(None);
+        };
+        *me.acc = (me.f)(me.acc, item);
+        Poll::Ready(Some(me.acc.clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let init = usize::from(!self.started);
+        let (lower, upper) = self.stream.size_hint();
+        (
+            lower.saturating_add(init),
+            upper.and_then(|upper| upper.checked_add(init)),
+        )
+    }
+}
@@ -0,0 +1,102 @@
+use std::future::Future;
+use std::mem;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+
+use futures_core::stream::Stream;
+use tokio::time::Instant;
+use tokio::time::Sleep;
+
+/// Groups items of `stream` into batches of at most `max_size`, flushing a batch early once
+/// `max_delay` has elapsed since its first item.
+///
+/// A partial batch is flushed when `stream` terminates.
+///
+/// # Panics
+///
+/// Panics if `max_size` is zero.
+pub fn batch<S: Stream>(
+    stream: S,
+    max_size: usize,
+    max_delay: Duration,
+) -> impl Stream<Item = Vec<S::Item>> {
+    assert!(max_size > 0, "max_size must be positive");
+    Batch {
+        stream,
+        max_size,
+        max_delay,
+        sleep: None,
+        items: Vec::new(),
+        done: false,
+    }
+}
+
+#[pin_project::pin_project]
+struct Batch<S: Stream> {
+    #[pin]
+    stream: S,
+    max_size: usize,
+    max_delay: Duration,
+    #[pin]
+    sleep: Option<Sleep>,
+    items: Vec<S::Item>,
+    done: bool,
+}
+
+impl<S: Stream> Stream for Batch<S> {
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        while !*me.done {
+            match me.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    if me.items.is_empty() {
+                        let deadline = Instant::now() + *me.max_delay;
+                        match me.sleep.as_mut().as_pin_mut() {
+                            Some(sleep) => sleep.reset(deadline),
+                            None => me.sleep.set(Some(tokio::time::sleep_until(deadline))),
+                        }
+                    }
+                    me.items.push(item);
+                    if me.items.len() == *me.max_size {
+                        return Poll::Ready(Some(mem::take(me.items)));
+                    }
+                }
+                Poll::Ready(None) => *me.done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        if me.items.is_empty() {
+            return if *me.done {
+                Poll::Ready(None)
+            } else {
+                Poll::Pending
+            };
+        }
+
+        // The timer is armed by the first item of a batch, so it is set whenever items are pending.
+        if !*me.done && me.sleep.as_pin_mut().unwrap().poll(cx).is_pending() {
+            return Poll::Pending;
+        }
+        Poll::Ready(Some(mem::take(me.items)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = usize::from(!self.items.is_empty());
+        if self.done {
+            return (pending, Some(pending));
+        }
+
+        let (lower, upper) = self.stream.size_hint();
+        let lower = lower
+            .saturating_add(self.items.len())
+            .div_ceil(self.max_size);
+        let upper = upper.and_then(|upper| upper.checked_add(self.items.len()));
+        (lower, upper)
+    }
+}
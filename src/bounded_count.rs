@@ -0,0 +1,76 @@
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Yields up to `max` items of `stream`, failing with `overflow_err()` if it produces more.
+///
+/// The item past the cap is dropped in favor of the error, and the stream terminates after it
+/// without polling `stream` again.
+pub fn bounded_count<S, E, F>(
+    stream: S,
+    max: usize,
+    overflow_err: F,
+) -> impl Stream<Item = Result<S::Item, E>>
+where
+    S: Stream,
+    F: FnOnce() -> E,
+{
+    BoundedCount {
+        stream,
+        remaining: max,
+        overflow_err: Some(overflow_err),
+    }
+}
+
+#[pin_project::pin_project]
+struct BoundedCount<S, F> {
+    #[pin]
+    stream: S,
+    remaining: usize,
+    overflow_err: Option<F>,
+}
+
+impl<S, E, F> Stream for BoundedCount<S, F>
+where
+    S: Stream,
+    F: FnOnce() -> E,
+{
+    type Item = Result<S::Item, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let me = self.project();
+
+        if me.overflow_err.is_none() {
+            return Poll::Ready(None);
+        }
+
+        let Some(item) = ready!(me.stream.poll_next(cx)) else {
+            *me.overflow_err = None;
+            return Poll::Ready(None);
+        };
+
+        if *me.remaining == 0 {
+            let overflow_err = me.overflow_err.take().unwrap();
+            return Poll::Ready(Some(Err(overflow_err())));
+        }
+
+        *me.remaining -= 1;
+        Poll::Ready(Some(Ok(item)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.overflow_err.is_none() {
+            return (0, Some(0));
+        }
+
+        let (lower, upper) = self.stream.size_hint();
+        let cap = self.remaining.saturating_add(1);
+        (
+            lower.min(cap),
+            Some(upper.map_or(cap, |upper| upper.min(cap))),
+        )
+    }
+}
@@ -0,0 +1,73 @@
+use std::mem;
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Accumulates items of `stream` into a buffer, yielding it whenever `should_flush` returns `true`
+/// for its contents. A non-empty remainder is yielded when `stream` ends.
+pub fn buffer_until<S, F>(stream: S, should_flush: F) -> impl Stream<Item = Vec<S::Item>>
+where
+    S: Stream,
+    F: FnMut(&[S::Item]) -> bool,
+{
+    BufferUntil {
+        stream,
+        should_flush,
+        buffer: Vec::new(),
+        done: false,
+    }
+}
+
+#[pin_project::pin_project]
+struct BufferUntil<S: Stream, F> {
+    #[pin]
+    stream: S,
+    should_flush: F,
+    buffer: Vec<S::Item>,
+    done: bool,
+}
+
+impl<S, F> Stream for BufferUntil<S, F>
+where
+    S: Stream,
+    F: FnMut(&[S::Item]) -> bool,
+{
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        if *me.done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            let Some(item) = ready!(me.stream.as_mut().poll_next(cx)) else {
+                *me.done = true;
+                let buffer = mem::take(me.buffer);
+                return Poll::Ready((!buffer.is_empty()).then_some(buffer));
+            };
+
+            me.buffer.push(item);
+            if (me.should_flush)(me.buffer) {
+                return Poll::Ready(Some(mem::take(me.buffer)));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        let buffered = self.buffer.len();
+        let (lower, upper) = self.stream.size_hint();
+        (
+            lower.saturating_add(buffered).min(1),
+            upper.and_then(|upper| upper.checked_add(buffered)),
+        )
+    }
+}
@@ -0,0 +1,67 @@
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Intercepts the first `Err` item of `stream` and terminates after it.
+///
+/// If `recover` maps the error to `Some(item)`, `Ok(item)` is yielded in its place; otherwise the
+/// error is yielded unchanged.
+pub fn catch<S, F, T, E>(stream: S, recover: F) -> impl Stream<Item = Result<T, E>>
+where
+    S: Stream<Item = Result<T, E>>,
+    F: FnMut(&E) -> Option<T>,
+{
+    Catch {
+        stream,
+        recover,
+        done: false,
+    }
+}
+
+#[pin_project::pin_project]
+struct Catch<S, F> {
+    #[pin]
+    stream: S,
+    recover: F,
+    done: bool,
+}
+
+impl<S, F, T, E> Stream for Catch<S, F>
+where
+    S: Stream<Item = Result<T, E>>,
+    F: FnMut(&E) -> Option<T>,
+{
+    type Item = Result<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let me = self.project();
+
+        if *me.done {
+            return Poll::Ready(None);
+        }
+
+        let item = match ready!(me.stream.poll_next(cx)) {
+            Some(Ok(item)) => Ok(item),
+            Some(Err(err)) => {
+                *me.done = true;
+                (me.recover)(&err).ok_or(err)
+            }
+            None => {
+                *me.done = true;
+                return Poll::Ready(None);
+            }
+        };
+        Poll::Ready(Some(item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.stream.size_hint().1)
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Yields all items of `first`, then all items of `second`.
+pub fn chain<A, B>(first: A, second: B) -> impl Stream<Item = A::Item>
+where
+    A: Stream,
+    B: Stream<Item = A::Item>,
+{
+    Chain {
+        first: Some(first),
+        second,
+    }
+}
+
+#[pin_project::pin_project]
+struct Chain<A, B> {
+    #[pin]
+    first: Option<A>,
+    #[pin]
+    second: B,
+}
+
+impl<A, B> Stream for Chain<A, B>
+where
+    A: Stream,
+    B: Stream<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        if let Some(first) = me.first.as_mut().as_pin_mut() {
+            match ready!(first.poll_next(cx)) {
+                Some(item) => return Poll::Ready(Some(item)),
+                None => me.first.set(None),
+            }
+        }
+
+        me.second.poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (second_lower, second_upper) = self.second.size_hint();
+        let Some(first) = &self.first else {
+            return (second_lower, second_upper);
+        };
+
+        let (first_lower, first_upper) = first.size_hint();
+        let upper = match (first_upper, second_upper) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _ => None,
+        };
+        (first_lower.saturating_add(second_lower), upper)
+    }
+}
@@ -0,0 +1,108 @@
+use std::mem;
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Batches items of `stream` so that the total `weight` of each batch stays within `max_weight`.
+///
+/// A batch is flushed once its weight reaches `max_weight`, or when the next item would push it
+/// over. An item heavier than `max_weight` forms a batch of its own, flushed right after the
+/// batch before it. A partial batch is flushed when `stream` ends.
+pub fn chunks_by_weight<S, F>(
+    stream: S,
+    max_weight: usize,
+    weight: F,
+) -> impl Stream<Item = Vec<S::Item>>
+where
+    S: Stream,
+    F: Fn(&S::Item) -> usize,
+{
+    ChunksByWeight {
+        stream,
+        max_weight,
+        weight,
+        batch: Vec::new(),
+        batch_weight: 0,
+        overweight: None,
+        done: false,
+    }
+}
+
+#[pin_project::pin_project]
+struct ChunksByWeight<S: Stream, F> {
+    #[pin]
+    stream: S,
+    max_weight: usize,
+    weight: F,
+    batch: Vec<S::Item>,
+    batch_weight: usize,
+    overweight: Option<S::Item>,
+    done: bool,
+}
+
+impl<S, F> Stream for ChunksByWeight<S, F>
+where
+    S: Stream,
+    F: Fn(&S::Item) -> usize,
+{
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        if let Some(item) = me.overweight.take() {
+            return Poll::Ready(Some(vec![item]));
+        }
+
+        if *me.done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            let Some(item) = ready!(me.stream.as_mut().poll_next(cx)) else {
+                *me.done = true;
+                *me.batch_weight = 0;
+                let batch = mem::take(me.batch);
+                return Poll::Ready((!batch.is_empty()).then_some(batch));
+            };
+
+            let weight = (me.weight)(&item);
+            if !me.batch.is_empty() && me.batch_weight.saturating_add(weight) > *me.max_weight {
+                let batch = mem::take(me.batch);
+                if weight >= *me.max_weight {
+                    // The item fills a batch on its own, so it is flushed on the next poll rather
+                    // than waiting for another item.
+                    *me.batch_weight = 0;
+                    *me.overweight = Some(item);
+                } else {
+                    *me.batch_weight = weight;
+                    me.batch.push(item);
+                }
+                return Poll::Ready(Some(batch));
+            }
+
+            me.batch.push(item);
+            *me.batch_weight = me.batch_weight.saturating_add(weight);
+            if *me.batch_weight >= *me.max_weight {
+                *me.batch_weight = 0;
+                return Poll::Ready(Some(mem::take(me.batch)));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        let batched = usize::from(!self.batch.is_empty()) + usize::from(self.overweight.is_some());
+        let (lower, upper) = self.stream.size_hint();
+        (
+            lower.saturating_add(batched).min(1),
+            upper.and_then(|upper| upper.checked_add(batched)),
+        )
+    }
+}
@@ -0,0 +1,82 @@
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Merges adjacent items of `stream` with `f`.
+///
+/// `f` receives the pending item and the next one: `Ok(merged)` keeps accumulating, while
+/// `Err((a, b))` yields `a` and continues with `b`. The pending item is yielded when `stream`
+/// ends.
+pub fn coalesce<S, F>(stream: S, f: F) -> impl Stream<Item = S::Item>
+where
+    S: Stream,
+    F: FnMut(S::Item, S::Item) -> Result<S::Item, (S::Item, S::Item)>,
+{
+    Coalesce {
+        stream,
+        f,
+        pending: None,
+        done: false,
+    }
+}
+
+#[pin_project::pin_project]
+struct Coalesce<S: Stream, F> {
+    #[pin]
+    stream: S,
+    f: F,
+    pending: Option<S::Item>,
+    done: bool,
+}
+
+impl<S, F> Stream for Coalesce<S, F>
+where
+    S: Stream,
+    F: FnMut(S::Item, S::Item) -> Result<S::Item, (S::Item, S::Item)>,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        if *me.done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            let Some(item) = ready!(me.stream.as_mut().poll_next(cx)) else {
+                *me.done = true;
+                return Poll::Ready(me.pending.take());
+            };
+
+            let Some(prev) = me.pending.take() else {
+                *me.pending = Some(item);
+                continue;
+            };
+
+            match (me.f)(prev, item) {
+                Ok(merged) => *me.pending = Some(merged),
+                Err((prev, item)) => {
+                    *me.pending = Some(item);
+                    return Poll::Ready(Some(prev));
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        let pending = usize::from(self.pending.is_some());
+        let (lower, upper) = self.stream.size_hint();
+        (
+            lower.saturating_add(pending).min(1),
+            upper.and_then(|upper| upper.checked_add(pending)),
+        )
+    }
+}
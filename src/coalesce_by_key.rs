@@ -0,0 +1,86 @@
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Merges runs of adjacent items of `stream` that share a `key` with `merge`.
+///
+/// The merged item of a run is yielded once an item with a different key arrives, or when
+/// `stream` ends. Items with equal keys that aren't adjacent are not merged.
+pub fn coalesce_by_key<S, K, F, M>(stream: S, key: F, merge: M) -> impl Stream<Item = S::Item>
+where
+    S: Stream,
+    K: Eq,
+    F: Fn(&S::Item) -> K,
+    M: Fn(S::Item, S::Item) -> S::Item,
+{
+    CoalesceByKey {
+        stream,
+        key,
+        merge,
+        pending: None,
+        done: false,
+    }
+}
+
+#[pin_project::pin_project]
+struct CoalesceByKey<S: Stream, K, F, M> {
+    #[pin]
+    stream: S,
+    key: F,
+    merge: M,
+    pending: Option<(K, S::Item)>,
+    done: bool,
+}
+
+impl<S, K, F, M> Stream for CoalesceByKey<S, K, F, M>
+where
+    S: Stream,
+    K: Eq,
+    F: Fn(&S::Item) -> K,
+    M: Fn(S::Item, S::Item) -> S::Item,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        if *me.done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            let Some(item) = ready!(me.stream.as_mut().poll_next(cx)) else {
+                *me.done = true;
+                return Poll::Ready(me.pending.take().map(|(_, item)| item));
+            };
+
+            let key = (me.key)(&item);
+            match me.pending.take() {
+                Some((prev_key, prev)) if prev_key == key => {
+                    *me.pending = Some((key, (me.merge)(prev, item)));
+                }
+                Some((_, prev)) => {
+                    *me.pending = Some((key, item));
+                    return Poll::Ready(Some(prev));
+                }
+                None => *me.pending = Some((key, item)),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        let pending = usize::from(self.pending.is_some());
+        let (lower, upper) = self.stream.size_hint();
+        (
+            lower.saturating_add(pending).min(1),
+            upper.and_then(|upper| upper.checked_add(pending)),
+        )
+    }
+}
@@ -0,0 +1,69 @@
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::FusedStream;
+use futures_core::stream::Stream;
+
+use crate::make_stream;
+use crate::Sender;
+
+/// Like [`make_stream`], but yields back to the executor after every `budget` items produced in
+/// a row.
+///
+/// Once `budget` items have been yielded without the generator going `Pending`, the next
+/// `poll_next` wakes the task and returns `Pending`, giving other tasks a chance to run.
+///
+/// # Panics
+///
+/// Panics if `budget` is zero.
+pub fn make_cooperative_stream<T>(
+    budget: usize,
+    closure: impl AsyncFnOnce(&mut Sender<T>) -> () + 'static,
+) -> impl FusedStream<Item = T> {
+    assert!(budget > 0, "budget must be positive");
+    Cooperative {
+        stream: make_stream(closure),
+        budget,
+        remaining: budget,
+    }
+}
+
+#[pin_project::pin_project]
+struct Cooperative<S> {
+    #[pin]
+    stream: S,
+    budget: usize,
+    remaining: usize,
+}
+
+impl<S: FusedStream> FusedStream for Cooperative<S> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<S: Stream> Stream for Cooperative<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let me = self.project();
+
+        if *me.remaining == 0 {
+            *me.remaining = *me.budget;
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+
+        let res = me.stream.poll_next(cx);
+        match res {
+            Poll::Ready(Some(_)) => *me.remaining -= 1,
+            Poll::Ready(None) | Poll::Pending => *me.remaining = *me.budget,
+        }
+        res
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
@@ -0,0 +1,81 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+
+use futures_core::stream::Stream;
+use tokio::time::Instant;
+use tokio::time::Sleep;
+
+/// Yields an item of `stream` only once `quiet` has elapsed without a newer item replacing it.
+///
+/// A pending item is flushed right away when `stream` terminates.
+pub fn debounce<S: Stream>(stream: S, quiet: Duration) -> impl Stream<Item = S::Item> {
+    Debounce {
+        stream,
+        quiet,
+        sleep: None,
+        pending: None,
+        done: false,
+    }
+}
+
+#[pin_project::pin_project]
+struct Debounce<S: Stream> {
+    #[pin]
+    stream: S,
+    quiet: Duration,
+    #[pin]
+    sleep: Option<Sleep>,
+    pending: Option<S::Item>,
+    done: bool,
+}
+
+impl<S: Stream> Stream for Debounce<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        while !*me.done {
+            match me.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    *me.pending = Some(item);
+                    let deadline = Instant::now() + *me.quiet;
+                    match me.sleep.as_mut().as_pin_mut() {
+                        Some(sleep) => sleep.reset(deadline),
+                        None => me.sleep.set(Some(tokio::time::sleep_until(deadline))),
+                    }
+                }
+                Poll::Ready(None) => *me.done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        if me.pending.is_none() {
+            return if *me.done {
+                Poll::Ready(None)
+            } else {
+                Poll::Pending
+            };
+        }
+
+        if !*me.done {
+            // The timer is armed by the first item, so it is set whenever an item is pending.
+            ready!(me.sleep.as_pin_mut().unwrap().poll(cx));
+        }
+        Poll::Ready(me.pending.take())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = usize::from(self.pending.is_some());
+        if self.done {
+            return (pending, Some(pending));
+        }
+
+        let upper = self.stream.size_hint().1;
+        (pending, upper.and_then(|upper| upper.checked_add(pending)))
+    }
+}
@@ -0,0 +1,72 @@
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::hash::Hash;
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Suppresses items equal to any of the last `window` distinct items emitted.
+///
+/// # Panics
+///
+/// Panics if `window` is zero.
+pub fn dedup_window<S>(stream: S, window: usize) -> impl Stream<Item = S::Item>
+where
+    S: Stream,
+    S::Item: Hash + Eq + Clone,
+{
+    assert!(window > 0, "window must be positive");
+    DedupWindow {
+        stream,
+        window,
+        order: VecDeque::with_capacity(window),
+        seen: HashSet::with_capacity(window),
+    }
+}
+
+#[pin_project::pin_project]
+struct DedupWindow<S: Stream> {
+    #[pin]
+    stream: S,
+    window: usize,
+    order: VecDeque<S::Item>,
+    seen: HashSet<S::Item>,
+}
+
+impl<S> Stream for DedupWindow<S>
+where
+    S: Stream,
+    S::Item: Hash + Eq + Clone,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        loop {
+            let Some(item) = ready!(me.stream.as_mut().poll_next(cx)) else {
+                return Poll::Ready(None);
+            };
+
+            if me.seen.contains(&item) {
+                continue;
+            }
+
+            if me.order.len() == *me.window {
+                if let Some(oldest) = me.order.pop_front() {
+                    me.seen.remove(&oldest);
+                }
+            }
+            me.order.push_back(item.clone());
+            me.seen.insert(item.clone());
+            return Poll::Ready(Some(item));
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.stream.size_hint().1)
+    }
+}
@@ -0,0 +1,67 @@
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+use crate::make_stream;
+use crate::Sender;
+
+/// Like [`make_stream`], but the generator works up to `lookahead` items ahead of the consumer.
+///
+/// Buffering happens inline: every time the stream is polled, the generator is driven until the
+/// buffer holds `lookahead` items beyond the one being returned, or the generator suspends. No
+/// task is spawned, so the generator need not be `Send`, but it only makes progress while the
+/// consumer polls, e.g. when the consumer's task is woken by the generator.
+pub fn make_eager_stream<T>(
+    lookahead: usize,
+    closure: impl AsyncFnOnce(&mut Sender<T>) -> () + 'static,
+) -> impl Stream<Item = T> {
+    EagerStream {
+        stream: make_stream(closure),
+        lookahead,
+        buffer: VecDeque::with_capacity(lookahead + 1),
+        done: false,
+    }
+}
+
+#[pin_project::pin_project]
+struct EagerStream<S: Stream> {
+    #[pin]
+    stream: S,
+    lookahead: usize,
+    buffer: VecDeque<S::Item>,
+    done: bool,
+}
+
+impl<S: Stream> Stream for EagerStream<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        while !*me.done && me.buffer.len() <= *me.lookahead {
+            match me.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => me.buffer.push_back(item),
+                Poll::Ready(None) => *me.done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        match me.buffer.pop_front() {
+            Some(item) => Poll::Ready(Some(item)),
+            None if *me.done => Poll::Ready(None),
+            None => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.buffer.len();
+        if self.done {
+            (buffered, Some(buffered))
+        } else {
+            (buffered, None)
+        }
+    }
+}
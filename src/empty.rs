@@ -0,0 +1,41 @@
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::FusedStream;
+use futures_core::stream::Stream;
+
+/// Creates a stream that is terminated from the start, without constructing a generator.
+pub const fn empty<T>() -> Empty<T> {
+    Empty { p: PhantomData }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Empty<T> {
+    p: PhantomData<fn() -> T>,
+}
+
+impl<T> Default for Empty<T> {
+    fn default() -> Empty<T> {
+        empty()
+    }
+}
+
+impl<T> FusedStream for Empty<T> {
+    fn is_terminated(&self) -> bool {
+        true
+    }
+}
+
+impl<T> Stream for Empty<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(None)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(0))
+    }
+}
@@ -0,0 +1,42 @@
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Pairs each item of `stream` with its index, counting from `start`.
+///
+/// The index advances once per yielded item, no matter how many polls return `Pending` in
+/// between.
+pub fn enumerate_from<S: Stream>(stream: S, start: usize) -> impl Stream<Item = (usize, S::Item)> {
+    EnumerateFrom {
+        stream,
+        index: start,
+    }
+}
+
+#[pin_project::pin_project]
+struct EnumerateFrom<S> {
+    #[pin]
+    stream: S,
+    index: usize,
+}
+
+impl<S: Stream> Stream for EnumerateFrom<S> {
+    type Item = (usize, S::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let me = self.project();
+        me.stream.poll_next(cx).map(|item| {
+            item.map(|item| {
+                let index = *me.index;
+                *me.index += 1;
+                (index, item)
+            })
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
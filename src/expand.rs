@@ -0,0 +1,64 @@
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+use std::vec;
+
+use futures_core::stream::Stream;
+
+/// Expands each item of `stream` into zero or more outputs of `f`, which also sees a state
+/// initialized to `init`.
+///
+/// The outputs of one item are drained before the next item is pulled.
+pub fn expand<S, St, F, U>(stream: S, init: St, f: F) -> impl Stream<Item = U>
+where
+    S: Stream,
+    F: FnMut(&mut St, S::Item) -> Vec<U>,
+{
+    Expand {
+        stream,
+        state: init,
+        f,
+        buffer: Vec::new().into_iter(),
+    }
+}
+
+#[pin_project::pin_project]
+struct Expand<S, St, F, U> {
+    #[pin]
+    stream: S,
+    state: St,
+    f: F,
+    buffer: vec::IntoIter<U>,
+}
+
+impl<S, St, F, U> Stream for Expand<S, St, F, U>
+where
+    S: Stream,
+    F: FnMut(&mut St, S::Item) -> Vec<U>,
+{
+    type Item = U;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        loop {
+            if let Some(item) = me.buffer.next() {
+                return Poll::Ready(Some(item));
+            }
+
+            let Some(item) = ready!(me.stream.as_mut().poll_next(cx)) else {
+                return Poll::Ready(None);
+            };
+            *me.buffer = (me.f)(me.state, item).into_iter();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.buffer.len();
+        match self.stream.size_hint() {
+            (_, Some(0)) => (buffered, Some(buffered)),
+            _ => (buffered, None),
+        }
+    }
+}
@@ -0,0 +1,279 @@
+use std::cmp;
+use std::collections::HashSet;
+use std::future;
+use std::future::Future;
+use std::hash::Hash;
+use std::iter;
+use std::pin::pin;
+use std::pin::Pin;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+use crate::next;
+
+/// Terminal operations on streams, implemented for every [`Stream`].
+pub trait AsyncStreamExt: Stream {
+    /// Pulls the next item of a try-stream, returning `Ok(None)` at its end and `Err` for an
+    /// error item.
+    ///
+    /// A [`make_try_stream`](crate::make_try_stream) stream usually terminates after its error
+    /// item, so every later call returns `Ok(None)`; see [`OnFail`](crate::OnFail).
+    fn try_next<T, E>(&mut self) -> impl Future<Output = Result<Option<T>, E>>
+    where
+        Self: Stream<Item = Result<T, E>> + Unpin,
+    {
+        async move { next(Pin::new(self)).await.transpose() }
+    }
+
+    /// Drains the stream and sums its items.
+    ///
+    /// Items are added to a running total as they arrive rather than buffered, so the total must
+    /// also be summable with itself.
+    fn sum<A>(self) -> impl Future<Output = A>
+    where
+        Self: Sized,
+        A: iter::Sum<Self::Item> + iter::Sum<A>,
+    {
+        async move {
+            let mut stream = pin!(self);
+            let mut total: A = iter::empty::<Self::Item>().sum();
+            while let Some(item) = next(stream.as_mut()).await {
+                let item: A = iter::once(item).sum();
+                total = [total, item].into_iter().sum();
+            }
+            total
+        }
+    }
+
+    /// Drains the stream and returns its minimum item, or `None` if it is empty.
+    ///
+    /// If several items are equally minimum, the first one is returned.
+    fn min(self) -> impl Future<Output = Option<Self::Item>>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        reduce(self, cmp::min)
+    }
+
+    /// Drains the stream and returns its maximum item, or `None` if it is empty.
+    ///
+    /// If several items are equally maximum, the last one is returned.
+    fn max(self) -> impl Future<Output = Option<Self::Item>>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        reduce(self, cmp::max)
+    }
+
+    /// Drains the stream, routing items for which `pred` returns `true` to the first collection
+    /// and the rest to the second.
+    fn partition<A, B, F>(self, pred: F) -> impl Future<Output = (A, B)>
+    where
+        Self: Sized,
+        A: Default + Extend<Self::Item>,
+        B: Default + Extend<Self::Item>,
+        F: Fn(&Self::Item) -> bool,
+    {
+        async move {
+            let mut stream = pin!(self);
+            let mut matched = A::default();
+            let mut unmatched = B::default();
+            while let Some(item) = next(stream.as_mut()).await {
+                if pred(&item) {
+                    matched.extend(Some(item));
+                } else {
+                    unmatched.extend(Some(item));
+                }
+            }
+            (matched, unmatched)
+        }
+    }
+
+    /// Drains a stream of `Result`s, collecting the `Ok` values into the first collection and
+    /// the errors into the second.
+    ///
+    /// Unlike [`try_for_each`](AsyncStreamExt::try_for_each), this does not stop at an error.
+    fn collect_results<T, E, A, B>(self) -> impl Future<Output = (A, B)>
+    where
+        Self: Stream<Item = Result<T, E>> + Sized,
+        A: FromIterator<T>,
+        B: FromIterator<E>,
+    {
+        async move {
+            let mut stream = pin!(self);
+            let mut oks = Vec::new();
+            let mut errs = Vec::new();
+            while let Some(item) = next(stream.as_mut()).await {
+                match item {
+                    Ok(value) => oks.push(value),
+                    Err(err) => errs.push(err),
+                }
+            }
+            (oks.into_iter().collect(), errs.into_iter().collect())
+        }
+    }
+
+    /// Drains the stream and collects the first occurrence of each distinct item, in the order
+    /// they were first seen.
+    fn distinct<C>(self) -> impl Future<Output = C>
+    where
+        Self: Sized,
+        Self::Item: Hash + Eq + Clone,
+        C: FromIterator<Self::Item>,
+    {
+        async move {
+            let mut stream = pin!(self);
+            let mut seen = HashSet::new();
+            let mut items = Vec::new();
+            while let Some(item) = next(stream.as_mut()).await {
+                if seen.insert(item.clone()) {
+                    items.push(item);
+                }
+            }
+            items.into_iter().collect()
+        }
+    }
+
+    /// Drains the stream, running `f` on each item with up to `limit` invocations in flight.
+    ///
+    /// A `limit` of `None` runs any number concurrently, while `1` runs them one after another.
+    /// Completes once the stream is exhausted and every invocation has finished.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is `Some(0)`.
+    fn for_each_concurrent<F>(
+        self,
+        limit: impl Into<Option<usize>>,
+        f: F,
+    ) -> impl Future<Output = ()>
+    where
+        Self: Sized,
+        F: AsyncFn(Self::Item),
+    {
+        let limit = limit.into();
+        assert!(limit != Some(0), "limit must be positive");
+
+        async move {
+            let f = &f;
+            let mut stream = pin!(self);
+            let mut in_flight = Vec::new();
+            let mut done = false;
+
+            future::poll_fn(|cx| loop {
+                while !done && limit.is_none_or(|limit| in_flight.len() < limit) {
+                    match stream.as_mut().poll_next(cx) {
+                        Poll::Ready(Some(item)) => in_flight.push(Box::pin(f.async_call((item,)))),
+                        Poll::Ready(None) => done = true,
+                        Poll::Pending => break,
+                    }
+                }
+
+                let running = in_flight.len();
+                in_flight.retain_mut(|fut| fut.as_mut().poll(cx).is_pending());
+
+                if done && in_flight.is_empty() {
+                    return Poll::Ready(());
+                }
+                if done || in_flight.len() == running {
+                    return Poll::Pending;
+                }
+            })
+            .await
+        }
+    }
+
+    /// Drains a try-stream, running `f` on each `Ok` item one after another.
+    ///
+    /// Stops at the first `Err`, whether it is an error item of the stream or returned by `f`.
+    fn try_for_each<T, E, F>(self, mut f: F) -> impl Future<Output = Result<(), E>>
+    where
+        Self: Stream<Item = Result<T, E>> + Sized,
+        F: AsyncFnMut(T) -> Result<(), E>,
+    {
+        async move {
+            let mut stream = pin!(self);
+            while let Some(item) = next(stream.as_mut()).await {
+                f.async_call_mut((item?,)).await?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Like [`try_for_each`](AsyncStreamExt::try_for_each), but with up to `limit` invocations of
+    /// `f` in flight; see [`for_each_concurrent`](AsyncStreamExt::for_each_concurrent).
+    ///
+    /// On the first `Err`, the invocations still in flight are dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is `Some(0)`.
+    fn try_for_each_concurrent<T, E, F>(
+        self,
+        limit: impl Into<Option<usize>>,
+        f: F,
+    ) -> impl Future<Output = Result<(), E>>
+    where
+        Self: Stream<Item = Result<T, E>> + Sized,
+        F: AsyncFn(T) -> Result<(), E>,
+    {
+        let limit = limit.into();
+        assert!(limit != Some(0), "limit must be positive");
+
+        async move {
+            let f = &f;
+            let mut stream = pin!(self);
+            let mut in_flight = Vec::new();
+            let mut done = false;
+
+            future::poll_fn(|cx| loop {
+                while !done && limit.is_none_or(|limit| in_flight.len() < limit) {
+                    match stream.as_mut().poll_next(cx) {
+                        Poll::Ready(Some(Ok(item))) => {
+                            in_flight.push(Box::pin(f.async_call((item,))))
+                        }
+                        Poll::Ready(Some(Err(err))) => return Poll::Ready(Err(err)),
+                        Poll::Ready(None) => done = true,
+                        Poll::Pending => break,
+                    }
+                }
+
+                let running = in_flight.len();
+                let mut i = 0;
+                while i < in_flight.len() {
+                    match in_flight[i].as_mut().poll(cx) {
+                        Poll::Ready(Ok(())) => drop(in_flight.swap_remove(i)),
+                        Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+                        Poll::Pending => i += 1,
+                    }
+                }
+
+                if done && in_flight.is_empty() {
+                    return Poll::Ready(Ok(()));
+                }
+                if done || in_flight.len() == running {
+                    return Poll::Pending;
+                }
+            })
+            .await
+        }
+    }
+}
+
+impl<S: Stream + ?Sized> AsyncStreamExt for S {}
+
+async fn reduce<S, F>(stream: S, mut f: F) -> Option<S::Item>
+where
+    S: Stream,
+    F: FnMut(S::Item, S::Item) -> S::Item,
+{
+    let mut stream = pin!(stream);
+    let mut acc = next(stream.as_mut()).await?;
+    while let Some(item) = next(stream.as_mut()).await {
+        acc = f(acc, item);
+    }
+    Some(acc)
+}
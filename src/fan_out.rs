@@ -0,0 +1,34 @@
+use std::future;
+use std::pin::pin;
+use std::pin::Pin;
+
+use futures_core::stream::Stream;
+use futures_sink::Sink;
+
+use crate::next;
+
+/// Drives `stream`, sending a clone of each item into every sink of `sinks`.
+///
+/// Completes once `stream` is exhausted and every sink has been flushed. Stops at the first sink
+/// error.
+pub async fn fan_out<S, K>(stream: S, sinks: Vec<K>) -> Result<(), K::Error>
+where
+    S: Stream,
+    S::Item: Clone,
+    K: Sink<S::Item>,
+{
+    let mut stream = pin!(stream);
+    let mut sinks: Vec<Pin<Box<K>>> = sinks.into_iter().map(Box::pin).collect();
+
+    while let Some(item) = next(stream.as_mut()).await {
+        for sink in &mut sinks {
+            future::poll_fn(|cx| sink.as_mut().poll_ready(cx)).await?;
+            sink.as_mut().start_send(item.clone())?;
+        }
+    }
+
+    for sink in &mut sinks {
+        future::poll_fn(|cx| sink.as_mut().poll_flush(cx)).await?;
+    }
+    Ok(())
+}
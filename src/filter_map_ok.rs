@@ -0,0 +1,51 @@
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Applies `f` to the `Ok` items of `stream`, dropping those mapped to `None`. `Err` items are
+/// passed through unchanged.
+pub fn filter_map_ok<S, F, T, U, E>(stream: S, f: F) -> impl Stream<Item = Result<U, E>>
+where
+    S: Stream<Item = Result<T, E>>,
+    F: FnMut(T) -> Option<U>,
+{
+    FilterMapOk { stream, f }
+}
+
+#[pin_project::pin_project]
+struct FilterMapOk<S, F> {
+    #[pin]
+    stream: S,
+    f: F,
+}
+
+impl<S, F, T, U, E> Stream for FilterMapOk<S, F>
+where
+    S: Stream<Item = Result<T, E>>,
+    F: FnMut(T) -> Option<U>,
+{
+    type Item = Result<U, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        loop {
+            match ready!(me.stream.as_mut().poll_next(cx)) {
+                Some(Ok(item)) => {
+                    if let Some(item) = (me.f)(item) {
+                        return Poll::Ready(Some(Ok(item)));
+                    }
+                }
+                Some(Err(err)) => return Poll::Ready(Some(Err(err))),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.stream.size_hint().1)
+    }
+}
@@ -0,0 +1,91 @@
+use std::future;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+
+use futures_core::stream::Stream;
+use tokio::time::error::Elapsed;
+use tokio::time::Timeout;
+
+/// Fails with [`Elapsed`] if `stream` doesn't yield its first item within `dur`.
+///
+/// The timer starts on the first poll and is disarmed once the first item arrives; later items may take arbitrarily long. If
+/// the timer fires first, a single `Err(Elapsed)` is yielded and the stream terminates.
+pub fn first_item_timeout<S: Stream>(
+    stream: S,
+    dur: Duration,
+) -> impl Stream<Item = Result<S::Item, Elapsed>> {
+    FirstItemTimeout {
+        stream,
+        dur,
+        timer: None,
+        armed: true,
+        done: false,
+    }
+}
+
+#[pin_project::pin_project]
+struct FirstItemTimeout<S> {
+    #[pin]
+    stream: S,
+    dur: Duration,
+    #[pin]
+    timer: Option<Timeout<future::Pending<()>>>,
+    armed: bool,
+    done: bool,
+}
+
+impl<S: Stream> Stream for FirstItemTimeout<S> {
+    type Item = Result<S::Item, Elapsed>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+        if *me.done {
+            return Poll::Ready(None);
+        }
+
+        if *me.armed && me.timer.is_none() {
+            me.timer
+                .set(Some(tokio::time::timeout(*me.dur, future::pending())));
+        }
+
+        match me.stream.poll_next(cx) {
+            Poll::Ready(Some(item)) => {
+                *me.armed = false;
+                me.timer.set(None);
+                return Poll::Ready(Some(Ok(item)));
+            }
+            Poll::Ready(None) => {
+                *me.done = true;
+                return Poll::Ready(None);
+            }
+            Poll::Pending => {}
+        }
+
+        if let Some(timer) = me.timer.as_mut().as_pin_mut() {
+            if let Poll::Ready(Err(elapsed)) = timer.poll(cx) {
+                *me.done = true;
+                *me.armed = false;
+                me.timer.set(None);
+                return Poll::Ready(Some(Err(elapsed)));
+            }
+        }
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        let (lower, upper) = self.stream.size_hint();
+        if self.armed {
+            // The timer may fire instead of any item arriving.
+            (0, upper.map(|upper| upper.max(1)))
+        } else {
+            (lower, upper)
+        }
+    }
+}
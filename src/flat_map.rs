@@ -0,0 +1,67 @@
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Maps each item of `stream` to a sub-stream and drains it fully before pulling the next item.
+pub fn flat_map<S, F, U>(stream: S, f: F) -> impl Stream<Item = U::Item>
+where
+    S: Stream,
+    F: FnMut(S::Item) -> U,
+    U: Stream,
+{
+    FlatMap {
+        stream,
+        f,
+        sub: None,
+    }
+}
+
+#[pin_project::pin_project]
+struct FlatMap<S, F, U> {
+    #[pin]
+    stream: S,
+    f: F,
+    #[pin]
+    sub: Option<U>,
+}
+
+impl<S, F, U> Stream for FlatMap<S, F, U>
+where
+    S: Stream,
+    F: FnMut(S::Item) -> U,
+    U: Stream,
+{
+    type Item = U::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        loop {
+            if let Some(sub) = me.sub.as_mut().as_pin_mut() {
+                match ready!(sub.poll_next(cx)) {
+                    Some(item) => return Poll::Ready(Some(item)),
+                    None => me.sub.set(None),
+                }
+            }
+
+            match ready!(me.stream.as_mut().poll_next(cx)) {
+                Some(item) => me.sub.set(Some((me.f)(item))),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (sub_lower, sub_upper) = self
+            .sub
+            .as_ref()
+            .map_or((0, Some(0)), |sub| sub.size_hint());
+        match self.stream.size_hint() {
+            (_, Some(0)) => (sub_lower, sub_upper),
+            _ => (sub_lower, None),
+        }
+    }
+}
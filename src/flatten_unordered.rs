@@ -0,0 +1,94 @@
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Drains up to `concurrency` sub-streams of `stream` at once, yielding their items as they
+/// arrive.
+///
+/// Sub-streams are polled round-robin, so a busy or infinite sub-stream does not starve the
+/// others.
+///
+/// # Panics
+///
+/// Panics if `concurrency` is zero.
+pub fn flatten_unordered<S>(
+    stream: S,
+    concurrency: usize,
+) -> impl Stream<Item = <S::Item as Stream>::Item>
+where
+    S: Stream,
+    S::Item: Stream,
+{
+    assert!(concurrency > 0, "concurrency must be positive");
+    FlattenUnordered {
+        stream,
+        concurrency,
+        done: false,
+        subs: Vec::with_capacity(concurrency),
+        cursor: 0,
+    }
+}
+
+#[pin_project::pin_project]
+struct FlattenUnordered<S: Stream> {
+    #[pin]
+    stream: S,
+    concurrency: usize,
+    done: bool,
+    subs: Vec<Pin<Box<S::Item>>>,
+    cursor: usize,
+}
+
+impl<S> Stream for FlattenUnordered<S>
+where
+    S: Stream,
+    S::Item: Stream,
+{
+    type Item = <S::Item as Stream>::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        loop {
+            while !*me.done && me.subs.len() < *me.concurrency {
+                match me.stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(sub)) => me.subs.push(Box::pin(sub)),
+                    Poll::Ready(None) => *me.done = true,
+                    Poll::Pending => break,
+                }
+            }
+
+            let mut finished = false;
+            let mut remaining = me.subs.len();
+            let mut i = *me.cursor;
+            while remaining > 0 {
+                i %= me.subs.len();
+                match me.subs[i].as_mut().poll_next(cx) {
+                    Poll::Ready(Some(item)) => {
+                        *me.cursor = i + 1;
+                        return Poll::Ready(Some(item));
+                    }
+                    Poll::Ready(None) => {
+                        drop(me.subs.remove(i));
+                        finished = true;
+                    }
+                    Poll::Pending => i += 1,
+                }
+                remaining -= 1;
+            }
+
+            // A finished sub-stream frees a slot for the next one.
+            if !finished || *me.done {
+                break;
+            }
+        }
+
+        if *me.done && me.subs.is_empty() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
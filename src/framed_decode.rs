@@ -0,0 +1,74 @@
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use bytes::Bytes;
+use bytes::BytesMut;
+use futures_core::stream::Stream;
+
+/// Decodes frames from the chunks of `byte_stream` with `decoder`.
+///
+/// Chunks are appended to a buffer, and `decoder` is called to take the next frame off its front:
+/// `Ok(None)` asks for more bytes, so a frame may span any number of chunks. Once `byte_stream`
+/// ends, `decoder` is called until it returns `Ok(None)` to flush the final frames; any bytes left
+/// over after that are discarded. The stream terminates after the first `Err`.
+pub fn framed_decode<S, T, E, D>(byte_stream: S, decoder: D) -> impl Stream<Item = Result<T, E>>
+where
+    S: Stream<Item = Bytes>,
+    D: FnMut(&mut BytesMut) -> Result<Option<T>, E>,
+{
+    FramedDecode {
+        stream: byte_stream,
+        decoder,
+        buf: BytesMut::new(),
+        eof: false,
+        done: false,
+    }
+}
+
+#[pin_project::pin_project]
+struct FramedDecode<S, D> {
+    #[pin]
+    stream: S,
+    decoder: D,
+    buf: BytesMut,
+    eof: bool,
+    done: bool,
+}
+
+impl<S, T, E, D> Stream for FramedDecode<S, D>
+where
+    S: Stream<Item = Bytes>,
+    D: FnMut(&mut BytesMut) -> Result<Option<T>, E>,
+{
+    type Item = Result<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        while !*me.done {
+            match (me.decoder)(me.buf) {
+                Ok(Some(frame)) => return Poll::Ready(Some(Ok(frame))),
+                Ok(None) if *me.eof => *me.done = true,
+                Ok(None) => match me.stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(chunk)) => me.buf.extend_from_slice(&chunk),
+                    Poll::Ready(None) => *me.eof = true,
+                    Poll::Pending => return Poll::Pending,
+                },
+                Err(err) => {
+                    *me.done = true;
+                    return Poll::Ready(Some(Err(err)));
+                }
+            }
+        }
+        Poll::Ready(None)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, None)
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+
+use futures_core::stream::Stream;
+use tokio::time::Instant;
+use tokio::time::Sleep;
+
+/// Yields `make_tick()` whenever `stream` produces no item within `interval`.
+///
+/// The timer starts on the first poll and is rearmed after every tick and every real item.
+pub fn heartbeat<S, F>(stream: S, interval: Duration, make_tick: F) -> impl Stream<Item = S::Item>
+where
+    S: Stream,
+    F: FnMut() -> S::Item,
+{
+    Heartbeat {
+        stream,
+        interval,
+        make_tick,
+        sleep: None,
+    }
+}
+
+#[pin_project::pin_project]
+struct Heartbeat<S, F> {
+    #[pin]
+    stream: S,
+    interval: Duration,
+    make_tick: F,
+    #[pin]
+    sleep: Option<Sleep>,
+}
+
+impl<S, F> Stream for Heartbeat<S, F>
+where
+    S: Stream,
+    F: FnMut() -> S::Item,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        if me.sleep.is_none() {
+            me.sleep.set(Some(tokio::time::sleep(*me.interval)));
+        }
+        let mut sleep = me.sleep.as_pin_mut().unwrap();
+
+        if let Poll::Ready(item) = me.stream.poll_next(cx) {
+            sleep.as_mut().reset(Instant::now() + *me.interval);
+            return Poll::Ready(item);
+        }
+
+        if sleep.as_mut().poll(cx).is_ready() {
+            sleep.as_mut().reset(Instant::now() + *me.interval);
+            return Poll::Ready(Some((me.make_tick)()));
+        }
+
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.stream.size_hint() {
+            (_, Some(0)) => (0, Some(0)),
+            (lower, _) => (lower, None),
+        }
+    }
+}
@@ -2,19 +2,183 @@
 #![feature(async_fn_traits)]
 
 use std::cell::Cell;
+use std::fmt;
+use std::future;
 use std::future::Future;
 use std::marker::PhantomData;
+use std::mem;
 use std::pin::Pin;
 use std::ptr;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::task::ready;
 use std::task::Context;
 use std::task::Poll;
 
 use futures_core::stream::FusedStream;
 use futures_core::stream::Stream;
 
+mod accumulate;
+#[cfg(feature = "tokio")]
+mod batch;
+mod bounded_count;
+mod buffer_until;
+mod catch;
+mod chain;
+mod chunks_by_weight;
+mod coalesce;
+mod coalesce_by_key;
+mod cooperative;
+#[cfg(feature = "tokio")]
+mod debounce;
+mod dedup_window;
+mod eager;
+mod empty;
+mod enumerate_from;
+mod expand;
+mod ext;
+mod fan_out;
+mod filter_map_ok;
+#[cfg(feature = "tokio")]
+mod first_item_timeout;
+mod flat_map;
+mod flatten_unordered;
+#[cfg(feature = "codec")]
+mod framed_decode;
+#[cfg(feature = "tokio")]
+mod heartbeat;
+mod limited;
+mod map_while;
+mod measure_rate;
+mod merge;
+mod merge_sorted;
+#[cfg(feature = "json")]
+mod ndjson;
+mod observed;
+mod ordered;
+#[cfg(feature = "tokio")]
+mod par_map;
+mod pausable;
+mod pending;
+#[cfg(feature = "tokio")]
+mod prefetch;
+pub mod prelude;
+mod ready_chunks;
+mod repeat;
+mod resumable;
+mod retry_map;
+mod rle;
+mod running;
+#[cfg(feature = "tokio")]
+mod sample;
+mod scan_err;
+mod select_all;
+mod split_on;
+mod state_machine;
+mod stateful_filter;
+mod then;
+mod timestamped;
+mod try_buffered;
+mod try_flatten;
+mod watchdog;
+mod with_shutdown;
+mod with_state;
+mod zip;
+mod zip_longest;
+
+pub use crate::accumulate::accumulate;
+#[cfg(feature = "tokio")]
+pub use crate::batch::batch;
+pub use crate::bounded_count::bounded_count;
+pub use crate::buffer_until::buffer_until;
+pub use crate::catch::catch;
+pub use crate::chain::chain;
+pub use crate::chunks_by_weight::chunks_by_weight;
+pub use crate::coalesce::coalesce;
+pub use crate::coalesce_by_key::coalesce_by_key;
+pub use crate::cooperative::make_cooperative_stream;
+#[cfg(feature = "tokio")]
+pub use crate::debounce::debounce;
+pub use crate::dedup_window::dedup_window;
+pub use crate::eager::make_eager_stream;
+pub use crate::empty::empty;
+pub use crate::empty::Empty;
+pub use crate::enumerate_from::enumerate_from;
+pub use crate::expand::expand;
+pub use crate::ext::AsyncStreamExt;
+pub use crate::fan_out::fan_out;
+pub use crate::filter_map_ok::filter_map_ok;
+#[cfg(feature = "tokio")]
+pub use crate::first_item_timeout::first_item_timeout;
+pub use crate::flat_map::flat_map;
+pub use crate::flatten_unordered::flatten_unordered;
+#[cfg(feature = "codec")]
+pub use crate::framed_decode::framed_decode;
+#[cfg(feature = "tokio")]
+pub use crate::heartbeat::heartbeat;
+pub use crate::limited::make_stream_limited;
+pub use crate::map_while::map_while;
+pub use crate::map_while::MapWhile;
+pub use crate::measure_rate::measure_rate;
+pub use crate::measure_rate::RateHandle;
+pub use crate::merge::merge;
+pub use crate::merge_sorted::merge_sorted;
+#[cfg(feature = "json")]
+pub use crate::ndjson::ndjson_encode;
+pub use crate::observed::make_stream_observed;
+pub use crate::observed::ObserveEvent;
+pub use crate::ordered::make_ordered_stream;
+pub use crate::ordered::OrderedSender;
+#[cfg(feature = "tokio")]
+pub use crate::par_map::par_map;
+pub use crate::pausable::pausable;
+pub use crate::pausable::PausableStream;
+pub use crate::pausable::PauseHandle;
+pub use crate::pending::pending;
+pub use crate::pending::Pending;
+#[cfg(feature = "tokio")]
+pub use crate::prefetch::prefetch;
+pub use crate::ready_chunks::ready_chunks;
+pub use crate::repeat::repeat;
+pub use crate::repeat::Repeat;
+pub use crate::resumable::make_resumable_stream;
+pub use crate::resumable::CheckpointHandle;
+pub use crate::resumable::ResumableSender;
+pub use crate::retry_map::retry_map;
+pub use crate::rle::rle_decode;
+pub use crate::rle::rle_encode;
+pub use crate::running::running_max;
+pub use crate::running::running_min;
+pub use crate::running::running_sum;
+#[cfg(feature = "tokio")]
+pub use crate::sample::sample;
+pub use crate::scan_err::scan_err;
+pub use crate::select_all::select_all;
+pub use crate::split_on::split_on;
+pub use crate::state_machine::make_state_machine;
+pub use crate::state_machine::Step;
+pub use crate::stateful_filter::stateful_filter;
+pub use crate::then::then;
+pub use crate::timestamped::timestamped;
+pub use crate::try_buffered::try_buffered;
+pub use crate::try_flatten::try_flatten;
+pub use crate::watchdog::make_watchdog_stream;
+pub use crate::with_shutdown::with_shutdown;
+pub use crate::with_state::make_stream_with_state;
+pub use crate::zip::zip;
+pub use crate::zip::zip_with;
+pub use crate::zip_longest::zip_longest;
+
+/// Creates a stream from a generator closure that yields items through the provided [`Sender`].
+///
+/// The generator may also be a free `async fn` taking `&mut Sender<T>`, passed by name.
+///
+/// When several sends race within one poll of the generator (e.g. under `join!`), they are
+/// emitted in the order they are polled. Use [`make_ordered_stream`] to emit them in the order
+/// they were created instead.
 pub fn make_stream<T>(
     closure: impl AsyncFnOnce(&mut Sender<T>) -> () + 'static,
-) -> impl Stream<Item = T> {
+) -> AsyncStream<T, impl Future<Output = ()>> {
     let (mut tx, rx) = pair::<T>();
     AsyncStream::new(rx, async move {
         closure.async_call_once((&mut tx,)).await;
@@ -23,9 +187,12 @@ pub fn make_stream<T>(
 
 pub fn make_try_stream<T, E>(
     closure: impl AsyncFnOnce(&mut TrySender<T, E>) -> Result<(), E> + 'static,
-) -> impl Stream<Item = Result<T, E>> {
+) -> AsyncStream<Result<T, E>, impl Future<Output = ()>> {
     let (tx, rx) = pair::<Result<T, E>>();
-    let mut tx = TrySender { sender: tx };
+    let mut tx = TrySender {
+        sender: tx,
+        on_fail: OnFail::Terminate,
+    };
     AsyncStream::new(rx, async move {
         let result = closure.async_call_once((&mut tx,)).await;
         if let Err(err) = result {
@@ -35,7 +202,6 @@ pub fn make_try_stream<T, E>(
 }
 
 #[pin_project::pin_project]
-#[derive(Debug)]
 pub struct AsyncStream<T, U> {
     rx: Receiver<T>,
     done: bool,
@@ -51,6 +217,52 @@ impl<T, U> AsyncStream<T, U> {
             generator,
         }
     }
+
+    fn hint(&self) -> (usize, Option<usize>) {
+        let buffered = usize::from(self.rx.slot.value.is_some());
+        if self.done {
+            (buffered, Some(buffered))
+        } else {
+            (buffered, None)
+        }
+    }
+}
+
+impl<T, U> AsyncStream<T, U>
+where
+    U: Future<Output = ()>,
+{
+    /// Advances the generator without consuming an item.
+    ///
+    /// An item produced by the generator is buffered and delivered by the next `poll_next`. While
+    /// an item is buffered, the generator is not polled and this returns `Ready` right away.
+    pub fn poll_progress(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let me = self.project();
+
+        if !*me.done && me.rx.slot.value.is_none() {
+            let res = {
+                let _enter = me.rx.enter();
+                me.generator.poll(cx)
+            };
+            *me.done = res.is_ready() || mem::take(&mut me.rx.slot.last);
+        }
+
+        if *me.done || me.rx.slot.value.is_some() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<T, U> fmt::Debug for AsyncStream<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncStream")
+            .field("done", &self.done)
+            .field("buffered", &self.rx.slot.value.is_some())
+            .field("size_hint", &self.hint())
+            .finish()
+    }
 }
 
 impl<T, U> FusedStream for AsyncStream<T, U>
@@ -58,7 +270,7 @@ where
     U: Future<Output = ()>,
 {
     fn is_terminated(&self) -> bool {
-        self.done
+        self.done && self.rx.slot.value.is_none()
     }
 }
 
@@ -68,75 +280,173 @@ where
 {
     type Item = T;
 
-    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        let me = self.project();
-
-        if *me.done {
-            return Poll::Ready(None);
-        }
-
-        let mut dst = None;
-        let res = {
-            let _enter = me.rx.enter(&mut dst);
-            me.generator.poll(cx)
-        };
-
-        *me.done = res.is_ready();
-
-        if dst.is_some() {
-            return Poll::Ready(dst.take());
-        }
-
-        if *me.done {
-            Poll::Ready(None)
-        } else {
-            Poll::Pending
-        }
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        ready!(self.as_mut().poll_progress(cx));
+        Poll::Ready(self.project().rx.slot.value.take())
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        if self.done {
-            (0, Some(0))
-        } else {
-            (0, None)
-        }
+        self.hint()
     }
 }
 
+async fn next<S: Stream + ?Sized>(mut stream: Pin<&mut S>) -> Option<S::Item> {
+    future::poll_fn(|cx| stream.as_mut().poll_next(cx)).await
+}
+
 thread_local!(static STORE: Cell<*mut ()> = const { Cell::new(ptr::null_mut()) });
 
+/// Hands out the ids that tie each [`Sender`] to the slot of its own stream.
+static NEXT_STREAM_ID: AtomicU64 = AtomicU64::new(0);
+
+fn with_slot<T, R>(stream_id: u64, f: impl FnOnce(&mut Slot<T>) -> R) -> R {
+    STORE.with(|cell| {
+        let ptr = cell.get();
+        assert!(
+            !ptr.is_null(),
+            "`Sender` used outside of its generator; sends must be awaited by the generator \
+             itself, not by a spawned task or another thread"
+        );
+
+        // Every slot is `repr(C)` and starts with the id of its stream, which is unique to the
+        // sender paired with it, so a matching id also means a matching item type.
+        #[allow(unsafe_code)]
+        let slot_stream_id = unsafe { *(ptr as *const u64) };
+        assert!(
+            slot_stream_id == stream_id,
+            "`Sender` used inside the generator of another stream; sends must be awaited by the \
+             generator of the stream they belong to",
+        );
+
+        #[allow(unsafe_code)]
+        let slot = unsafe { &mut *(ptr as *mut Slot<T>) };
+        f(slot)
+    })
+}
+
 fn pair<T>() -> (Sender<T>, Receiver<T>) {
-    let tx = Sender { p: PhantomData };
-    let rx = Receiver { p: PhantomData };
+    let stream_id = NEXT_STREAM_ID.fetch_add(1, Ordering::Relaxed);
+    let tx = Sender {
+        stream_id,
+        p: PhantomData,
+    };
+    let rx = Receiver {
+        slot: Slot {
+            stream_id,
+            demand: Demand::Ready,
+            last: false,
+            value: None,
+        },
+    };
     (tx, rx)
 }
 
+/// The handle a [`make_try_stream`] generator yields items and errors through.
 #[derive(Debug)]
 pub struct TrySender<T, E> {
     sender: Sender<Result<T, E>>,
+    on_fail: OnFail,
+}
+
+/// What happens to a [`make_try_stream`] stream after [`TrySender::fail`] emits an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OnFail {
+    /// The stream terminates right after the error, and the generator is not resumed.
+    #[default]
+    Terminate,
+    /// The generator is resumed after the error, and may keep sending items.
+    Continue,
 }
 
 impl<T, E> TrySender<T, E> {
-    pub fn send(&mut self, value: T) -> impl Future<Output = ()> {
-        Send {
-            value: Some(Ok::<T, E>(value)),
+    pub fn send(&self, value: T) -> impl Future<Output = ()> + '_ {
+        Send::new(self.sender.stream_id, Ok::<T, E>(value))
+    }
+
+    /// Configures what happens after [`TrySender::fail`]; see [`OnFail`].
+    ///
+    /// Defaults to [`OnFail::Terminate`], which matches returning the error from the generator.
+    pub fn on_fail(&mut self, mode: OnFail) -> &mut Self {
+        self.on_fail = mode;
+        self
+    }
+
+    /// Emits `err` as an error item of the stream.
+    ///
+    /// With [`OnFail::Terminate`], the stream terminates once the error has been taken, so the
+    /// returned future never completes. With [`OnFail::Continue`], it completes like a send.
+    pub fn fail(&self, err: E) -> impl Future<Output = ()> + '_ {
+        let send = Send::new(self.sender.stream_id, Err::<T, E>(err));
+        match self.on_fail {
+            OnFail::Terminate => Send { last: true, ..send },
+            OnFail::Continue => send,
         }
     }
+
+    /// See [`Sender::demand`].
+    pub fn demand(&self) -> Demand {
+        self.sender.demand()
+    }
+}
+
+/// A heuristic signal of whether the consumer is keeping up with the generator.
+///
+/// The stream takes at most one item per poll, so a send that finds the item slot still occupied
+/// (e.g. by a racing send) has to wait for a later poll. This only reflects what the stream
+/// observes between polls; it is not a guarantee about the consumer's capacity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Demand {
+    /// The last send was taken on the first poll it was offered.
+    Ready,
+    /// The last send had to wait for the given number of additional polls before it was taken.
+    Backpressure { waited_polls: usize },
 }
 
+/// The handle a [`make_stream`] generator yields items through.
+///
+/// The generator only borrows the sender, and the futures returned by [`Sender::send`] borrow it
+/// in turn, so neither can outlive the generator call or be moved into a spawned task. The
+/// sender is still `Sync`, though, so a send can be awaited on a scoped thread while the generator
+/// waits; such a send panics with a targeted message rather than misdelivering the item.
+///
+/// Generators, and any user code an adapter runs inside its own generator (e.g. the function
+/// passed to [`retry_map`]), must be `'static`, so they can't capture the sender of another
+/// stream either, e.g. that of an enclosing generator.
 #[derive(Debug)]
 pub struct Sender<T> {
+    stream_id: u64,
     p: PhantomData<fn(T) -> T>,
 }
 
 impl<T> Sender<T> {
-    pub fn send(&mut self, value: T) -> impl Future<Output = ()> {
-        Send { value: Some(value) }
+    pub fn send(&self, value: T) -> impl Future<Output = ()> + '_ {
+        Send::new(self.stream_id, value)
+    }
+
+    /// Reports whether the last completed send was taken immediately; see [`Demand`].
+    ///
+    /// Must be called from within the generator.
+    pub fn demand(&self) -> Demand {
+        with_slot::<T, _>(self.stream_id, |slot| slot.demand)
     }
 }
 
 struct Send<T> {
+    stream_id: u64,
     value: Option<T>,
+    waited_polls: usize,
+    last: bool,
+}
+
+impl<T> Send<T> {
+    fn new(stream_id: u64, value: T) -> Send<T> {
+        Send {
+            stream_id,
+            value: Some(value),
+            waited_polls: 0,
+            last: false,
+        }
+    }
 }
 
 impl<T> Unpin for Send<T> {}
@@ -149,13 +459,16 @@ impl<T> Future for Send<T> {
             return Poll::Ready(());
         }
 
-        STORE.with(|cell| {
-            let ptr = cell.get() as *mut Option<T>;
-            #[allow(unsafe_code)]
-            let option_ref = unsafe { ptr.as_mut() }.expect("invalid usage");
-
-            if option_ref.is_none() {
-                *option_ref = self.value.take();
+        with_slot(self.stream_id, |slot| {
+            if slot.value.is_none() {
+                slot.value = self.value.take();
+                slot.last = self.last;
+                slot.demand = match self.waited_polls {
+                    0 => Demand::Ready,
+                    waited_polls => Demand::Backpressure { waited_polls },
+                };
+            } else {
+                self.waited_polls += 1;
             }
 
             Poll::Pending
@@ -165,7 +478,16 @@ impl<T> Future for Send<T> {
 
 #[derive(Debug)]
 struct Receiver<T> {
-    p: PhantomData<T>,
+    slot: Slot<T>,
+}
+
+#[derive(Debug)]
+#[repr(C)]
+struct Slot<T> {
+    stream_id: u64,
+    demand: Demand,
+    last: bool,
+    value: Option<T>,
 }
 
 struct Enter<'a, T> {
@@ -175,10 +497,10 @@ struct Enter<'a, T> {
 }
 
 impl<T> Receiver<T> {
-    pub(crate) fn enter<'a>(&'a mut self, dst: &'a mut Option<T>) -> Enter<'a, T> {
+    pub(crate) fn enter(&mut self) -> Enter<'_, T> {
         let prev = STORE.with(|cell| {
             let prev = cell.get();
-            cell.set(dst as *mut _ as *mut ());
+            cell.set(&mut self.slot as *mut _ as *mut ());
             prev
         });
 
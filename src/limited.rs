@@ -0,0 +1,64 @@
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::FusedStream;
+use futures_core::stream::Stream;
+
+use crate::make_stream;
+use crate::Sender;
+
+/// Like [`make_stream`], but panics on the `poll_next` call after the first `max_polls`.
+///
+/// This is a safety valve for tests that might otherwise hang on an infinite generator: running
+/// out of the budget fails the test loudly instead of ending the stream as if it had completed.
+/// It is not meant for production flow control.
+pub fn make_stream_limited<T>(
+    max_polls: usize,
+    closure: impl AsyncFnOnce(&mut Sender<T>) -> () + 'static,
+) -> impl FusedStream<Item = T> {
+    Limited {
+        stream: make_stream(closure),
+        remaining: max_polls,
+        max_polls,
+    }
+}
+
+#[pin_project::pin_project]
+struct Limited<S> {
+    #[pin]
+    stream: S,
+    remaining: usize,
+    max_polls: usize,
+}
+
+impl<S: FusedStream> FusedStream for Limited<S> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<S: FusedStream> Stream for Limited<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let me = self.project();
+
+        if me.stream.is_terminated() {
+            return Poll::Ready(None);
+        }
+
+        assert!(
+            *me.remaining > 0,
+            "stream exhausted its budget of {} polls without terminating",
+            me.max_polls
+        );
+
+        *me.remaining -= 1;
+        me.stream.poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
@@ -0,0 +1,67 @@
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::FusedStream;
+use futures_core::stream::Stream;
+
+/// Maps items of `stream` through `f` until it first returns `None`, at which point the inner
+/// stream is dropped and the stream terminates.
+pub fn map_while<S, F, U>(stream: S, f: F) -> MapWhile<S, F>
+where
+    S: Stream,
+    F: FnMut(S::Item) -> Option<U>,
+{
+    MapWhile {
+        stream: Some(stream),
+        f,
+    }
+}
+
+#[pin_project::pin_project]
+#[derive(Debug)]
+pub struct MapWhile<S, F> {
+    #[pin]
+    stream: Option<S>,
+    f: F,
+}
+
+impl<S, F, U> FusedStream for MapWhile<S, F>
+where
+    S: Stream,
+    F: FnMut(S::Item) -> Option<U>,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_none()
+    }
+}
+
+impl<S, F, U> Stream for MapWhile<S, F>
+where
+    S: Stream,
+    F: FnMut(S::Item) -> Option<U>,
+{
+    type Item = U;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        let Some(stream) = me.stream.as_mut().as_pin_mut() else {
+            return Poll::Ready(None);
+        };
+
+        let item = ready!(stream.poll_next(cx)).and_then(me.f);
+        if item.is_none() {
+            me.stream.set(None);
+        }
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.stream {
+            Some(stream) => (0, stream.size_hint().1),
+            None => (0, Some(0)),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use std::pin::Pin;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::OnceLock;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Instant;
+
+use futures_core::stream::Stream;
+
+/// Wraps `stream` to measure its throughput, reported by the returned [`RateHandle`].
+///
+/// The clock starts at the first poll and stops when `stream` ends.
+pub fn measure_rate<S: Stream>(stream: S) -> (impl Stream<Item = S::Item>, RateHandle) {
+    let handle = RateHandle {
+        state: Arc::new(RateState::default()),
+    };
+    let stream = MeasureRate {
+        stream,
+        state: handle.state.clone(),
+    };
+    (stream, handle)
+}
+
+#[derive(Debug, Default)]
+struct RateState {
+    items: AtomicU64,
+    start: OnceLock<Instant>,
+    end: OnceLock<Instant>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RateHandle {
+    state: Arc<RateState>,
+}
+
+impl RateHandle {
+    /// The number of items yielded so far.
+    pub fn items(&self) -> u64 {
+        self.state.items.load(Ordering::Relaxed)
+    }
+
+    /// The observed throughput, or zero if the stream has not been polled yet.
+    pub fn items_per_sec(&self) -> f64 {
+        let Some(start) = self.state.start.get() else {
+            return 0.0;
+        };
+
+        let end = self.state.end.get().copied().unwrap_or_else(Instant::now);
+        let elapsed = end.duration_since(*start).as_secs_f64();
+        if elapsed > 0.0 {
+            self.items() as f64 / elapsed
+        } else {
+            0.0
+        }
+    }
+}
+
+#[pin_project::pin_project]
+struct MeasureRate<S> {
+    #[pin]
+    stream: S,
+    state: Arc<RateState>,
+}
+
+impl<S: Stream> Stream for MeasureRate<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let me = self.project();
+
+        me.state.start.get_or_init(Instant::now);
+        let res = me.stream.poll_next(cx);
+        match &res {
+            Poll::Ready(Some(_)) => {
+                me.state.items.fetch_add(1, Ordering::Relaxed);
+            }
+            Poll::Ready(None) => {
+                me.state.end.get_or_init(Instant::now);
+            }
+            Poll::Pending => {}
+        }
+        res
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
@@ -0,0 +1,90 @@
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Interleaves items of `a` and `b` as they become available, terminating once both are
+/// exhausted.
+///
+/// The two streams take turns being polled first, so neither starves the other.
+pub fn merge<A, B>(a: A, b: B) -> impl Stream<Item = A::Item>
+where
+    A: Stream,
+    B: Stream<Item = A::Item>,
+{
+    Merge {
+        a,
+        b,
+        a_done: false,
+        b_done: false,
+        b_first: false,
+    }
+}
+
+#[pin_project::pin_project]
+struct Merge<A, B> {
+    #[pin]
+    a: A,
+    #[pin]
+    b: B,
+    a_done: bool,
+    b_done: bool,
+    b_first: bool,
+}
+
+fn poll_side<S: Stream>(
+    stream: Pin<&mut S>,
+    done: &mut bool,
+    cx: &mut Context<'_>,
+) -> Option<S::Item> {
+    if *done {
+        return None;
+    }
+
+    match stream.poll_next(cx) {
+        Poll::Ready(Some(item)) => Some(item),
+        Poll::Ready(None) => {
+            *done = true;
+            None
+        }
+        Poll::Pending => None,
+    }
+}
+
+impl<A, B> Stream for Merge<A, B>
+where
+    A: Stream,
+    B: Stream<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let me = self.project();
+
+        *me.b_first = !*me.b_first;
+        let item = if *me.b_first {
+            poll_side(me.b, me.b_done, cx).or_else(|| poll_side(me.a, me.a_done, cx))
+        } else {
+            poll_side(me.a, me.a_done, cx).or_else(|| poll_side(me.b, me.b_done, cx))
+        };
+
+        match item {
+            Some(item) => Poll::Ready(Some(item)),
+            None if *me.a_done && *me.b_done => Poll::Ready(None),
+            None => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let side =
+            |done: bool, hint: (usize, Option<usize>)| if done { (0, Some(0)) } else { hint };
+        let (a_lower, a_upper) = side(self.a_done, self.a.size_hint());
+        let (b_lower, b_upper) = side(self.b_done, self.b.size_hint());
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _ => None,
+        };
+        (a_lower.saturating_add(b_lower), upper)
+    }
+}
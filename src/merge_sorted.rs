@@ -0,0 +1,107 @@
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Merges the items of `a` and `b`, each sorted in ascending order, into one sorted stream.
+///
+/// The head item of each side is held back until the other side's head is known, and equal items
+/// are yielded from `a` first. Once one stream has finished, the rest of the other is drained.
+pub fn merge_sorted<A, B>(a: A, b: B) -> impl Stream<Item = A::Item>
+where
+    A: Stream,
+    A::Item: Ord,
+    B: Stream<Item = A::Item>,
+{
+    MergeSorted {
+        a,
+        b,
+        head_a: None,
+        head_b: None,
+        a_done: false,
+        b_done: false,
+    }
+}
+
+#[pin_project::pin_project]
+struct MergeSorted<A: Stream, B> {
+    #[pin]
+    a: A,
+    #[pin]
+    b: B,
+    head_a: Option<A::Item>,
+    head_b: Option<A::Item>,
+    a_done: bool,
+    b_done: bool,
+}
+
+impl<A, B> Stream for MergeSorted<A, B>
+where
+    A: Stream,
+    A::Item: Ord,
+    B: Stream<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let me = self.project();
+
+        if !*me.a_done && me.head_a.is_none() {
+            match me.a.poll_next(cx) {
+                Poll::Ready(Some(item)) => *me.head_a = Some(item),
+                Poll::Ready(None) => *me.a_done = true,
+                Poll::Pending => {}
+            }
+        }
+
+        if !*me.b_done && me.head_b.is_none() {
+            match me.b.poll_next(cx) {
+                Poll::Ready(Some(item)) => *me.head_b = Some(item),
+                Poll::Ready(None) => *me.b_done = true,
+                Poll::Pending => {}
+            }
+        }
+
+        let a_settled = *me.a_done || me.head_a.is_some();
+        let b_settled = *me.b_done || me.head_b.is_some();
+        if !a_settled || !b_settled {
+            return Poll::Pending;
+        }
+
+        let item = match (me.head_a.take(), me.head_b.take()) {
+            (Some(a), Some(b)) if b < a => {
+                *me.head_a = Some(a);
+                Some(b)
+            }
+            (Some(a), b) => {
+                *me.head_b = b;
+                Some(a)
+            }
+            (None, b) => b,
+        };
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let side = |done: bool, head: bool, (lower, upper): (usize, Option<usize>)| {
+            let head = usize::from(head);
+            if done {
+                (head, Some(head))
+            } else {
+                (
+                    lower.saturating_add(head),
+                    upper.and_then(|upper| upper.checked_add(head)),
+                )
+            }
+        };
+        let (a_lower, a_upper) = side(self.a_done, self.head_a.is_some(), self.a.size_hint());
+        let (b_lower, b_upper) = side(self.b_done, self.head_b.is_some(), self.b.size_hint());
+
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _ => None,
+        };
+        (a_lower.saturating_add(b_lower), upper)
+    }
+}
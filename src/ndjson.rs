@@ -0,0 +1,46 @@
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+use serde::Serialize;
+
+/// Serializes each item of `stream` as a line of newline-delimited JSON.
+///
+/// Every `Ok` item is one JSON document followed by `\n`. An item that fails to serialize yields
+/// an `Err` without ending the stream.
+pub fn ndjson_encode<S>(stream: S) -> impl Stream<Item = Result<Vec<u8>, serde_json::Error>>
+where
+    S: Stream,
+    S::Item: Serialize,
+{
+    NdjsonEncode { stream }
+}
+
+#[pin_project::pin_project]
+struct NdjsonEncode<S> {
+    #[pin]
+    stream: S,
+}
+
+impl<S> Stream for NdjsonEncode<S>
+where
+    S: Stream,
+    S::Item: Serialize,
+{
+    type Item = Result<Vec<u8>, serde_json::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().stream.poll_next(cx).map(|item| {
+            item.map(|item| {
+                let mut line = serde_json::to_vec(&item)?;
+                line.push(b'\n');
+                Ok(line)
+            })
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
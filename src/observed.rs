@@ -0,0 +1,78 @@
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::FusedStream;
+use futures_core::stream::Stream;
+
+use crate::make_stream;
+use crate::Sender;
+
+/// Like [`make_stream`], but reports each step of `poll_next` to `observer`.
+///
+/// Every poll first reports [`ObserveEvent::Polled`], then how it ended. A yielded item is
+/// reported by reference right before it is returned.
+pub fn make_stream_observed<T>(
+    observer: impl FnMut(ObserveEvent<&T>),
+    closure: impl AsyncFnOnce(&mut Sender<T>) -> () + 'static,
+) -> impl FusedStream<Item = T> {
+    Observed {
+        stream: make_stream(closure),
+        observer,
+    }
+}
+
+/// A step of `poll_next` reported by [`make_stream_observed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObserveEvent<R> {
+    /// `poll_next` was called.
+    Polled,
+    /// `poll_next` is about to return this item.
+    Yielded(R),
+    /// `poll_next` returned `Pending`.
+    Pending,
+    /// `poll_next` returned `None`.
+    Terminated,
+}
+
+#[pin_project::pin_project]
+struct Observed<S, F> {
+    #[pin]
+    stream: S,
+    observer: F,
+}
+
+impl<S, F> FusedStream for Observed<S, F>
+where
+    S: FusedStream,
+    F: FnMut(ObserveEvent<&S::Item>),
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<S, F> Stream for Observed<S, F>
+where
+    S: Stream,
+    F: FnMut(ObserveEvent<&S::Item>),
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let me = self.project();
+
+        (me.observer)(ObserveEvent::Polled);
+        let res = me.stream.poll_next(cx);
+        match &res {
+            Poll::Ready(Some(item)) => (me.observer)(ObserveEvent::Yielded(item)),
+            Poll::Ready(None) => (me.observer)(ObserveEvent::Terminated),
+            Poll::Pending => (me.observer)(ObserveEvent::Pending),
+        }
+        res
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
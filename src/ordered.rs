@@ -0,0 +1,100 @@
+use std::collections::BTreeSet;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task::Context;
+use std::task::Poll;
+
+use crate::pair;
+use crate::AsyncStream;
+use crate::Send;
+
+/// Like [`make_stream`](crate::make_stream), but concurrent sends are emitted in the order their
+/// `send` futures were created, regardless of the order they are polled in.
+///
+/// A send waits until every earlier send has been emitted or dropped, so an earlier send that is
+/// never polled blocks all later ones.
+pub fn make_ordered_stream<T>(
+    closure: impl AsyncFnOnce(&mut OrderedSender<T>) -> () + 'static,
+) -> AsyncStream<T, impl Future<Output = ()>> {
+    let (tx, rx) = pair::<T>();
+    let mut tx = OrderedSender {
+        stream_id: tx.stream_id,
+        order: Arc::new(Mutex::new(Order::default())),
+        p: PhantomData,
+    };
+    AsyncStream::new(rx, async move {
+        closure.async_call_once((&mut tx,)).await;
+    })
+}
+
+#[derive(Debug, Default)]
+struct Order {
+    next_seq: u64,
+    pending: BTreeSet<u64>,
+}
+
+#[derive(Debug)]
+pub struct OrderedSender<T> {
+    stream_id: u64,
+    order: Arc<Mutex<Order>>,
+    p: PhantomData<fn(T) -> T>,
+}
+
+impl<T> OrderedSender<T> {
+    pub fn send(&self, value: T) -> impl Future<Output = ()> + '_ {
+        let seq = {
+            let mut order = self.order.lock().unwrap();
+            let seq = order.next_seq;
+            order.next_seq += 1;
+            order.pending.insert(seq);
+            seq
+        };
+
+        OrderedSend {
+            send: Send::new(self.stream_id, value),
+            seq,
+            order: self.order.clone(),
+        }
+    }
+}
+
+struct OrderedSend<T> {
+    send: Send<T>,
+    seq: u64,
+    order: Arc<Mutex<Order>>,
+}
+
+impl<T> Unpin for OrderedSend<T> {}
+
+impl<T> Future for OrderedSend<T> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let me = self.get_mut();
+        if me.send.value.is_none() {
+            return Poll::Ready(());
+        }
+
+        let mut order = me.order.lock().unwrap();
+        if order.pending.first() != Some(&me.seq) {
+            return Poll::Pending;
+        }
+
+        let res = Pin::new(&mut me.send).poll(cx);
+        if me.send.value.is_none() {
+            order.pending.remove(&me.seq);
+        }
+        res
+    }
+}
+
+impl<T> Drop for OrderedSend<T> {
+    fn drop(&mut self) {
+        if let Ok(mut order) = self.order.lock() {
+            order.pending.remove(&self.seq);
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use std::collections::VecDeque;
+use std::future::Future;
+use std::panic;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+use tokio::task::JoinHandle;
+
+/// Runs `f` on each item of `stream` on Tokio's blocking thread pool, with at most `concurrency`
+/// invocations in flight.
+///
+/// With `ordered` set, results are yielded in input order; otherwise they are yielded as they
+/// complete. Blocking tasks cannot be cancelled, so dropping the stream detaches any that are
+/// still running.
+///
+/// # Panics
+///
+/// Panics if `concurrency` is zero, or resumes the panic if `f` panics.
+pub fn par_map<S, F, U>(stream: S, f: F, concurrency: usize, ordered: bool) -> impl Stream<Item = U>
+where
+    S: Stream,
+    S::Item: Send + 'static,
+    F: Fn(S::Item) -> U + Send + Sync + 'static,
+    U: Send + 'static,
+{
+    assert!(concurrency > 0, "concurrency must be positive");
+    ParMap {
+        stream,
+        f: Arc::new(f),
+        concurrency,
+        ordered,
+        done: false,
+        in_flight: VecDeque::with_capacity(concurrency),
+    }
+}
+
+#[pin_project::pin_project]
+struct ParMap<S, F, U> {
+    #[pin]
+    stream: S,
+    f: Arc<F>,
+    concurrency: usize,
+    ordered: bool,
+    done: bool,
+    in_flight: VecDeque<JoinHandle<U>>,
+}
+
+impl<S, F, U> Stream for ParMap<S, F, U>
+where
+    S: Stream,
+    S::Item: Send + 'static,
+    F: Fn(S::Item) -> U + Send + Sync + 'static,
+    U: Send + 'static,
+{
+    type Item = U;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        while !*me.done && me.in_flight.len() < *me.concurrency {
+            match me.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    let f = me.f.clone();
+                    me.in_flight
+                        .push_back(tokio::task::spawn_blocking(move || f(item)));
+                }
+                Poll::Ready(None) => *me.done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        let polled = if *me.ordered {
+            me.in_flight.len().min(1)
+        } else {
+            me.in_flight.len()
+        };
+
+        for i in 0..polled {
+            if let Poll::Ready(res) = Pin::new(&mut me.in_flight[i]).poll(cx) {
+                me.in_flight.remove(i);
+                return match res {
+                    Ok(item) => Poll::Ready(Some(item)),
+                    Err(err) => panic::resume_unwind(err.into_panic()),
+                };
+            }
+        }
+
+        if *me.done && me.in_flight.is_empty() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let in_flight = self.in_flight.len();
+        if self.done {
+            return (in_flight, Some(in_flight));
+        }
+
+        let (lower, upper) = self.stream.size_hint();
+        (
+            lower.saturating_add(in_flight),
+            upper.and_then(|upper| upper.checked_add(in_flight)),
+        )
+    }
+}
@@ -0,0 +1,92 @@
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task::Context;
+use std::task::Poll;
+use std::task::Waker;
+
+use futures_core::stream::FusedStream;
+use futures_core::stream::Stream;
+
+/// Wraps `stream` so that it can be paused and resumed through the returned [`PauseHandle`].
+///
+/// While paused, the inner stream is not polled.
+pub fn pausable<S: Stream>(stream: S) -> (PausableStream<S>, PauseHandle) {
+    let handle = PauseHandle {
+        state: Arc::new(Mutex::new(PauseState::default())),
+    };
+    let stream = PausableStream {
+        stream,
+        handle: handle.clone(),
+    };
+    (stream, handle)
+}
+
+#[derive(Debug, Default)]
+struct PauseState {
+    paused: bool,
+    waker: Option<Waker>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PauseHandle {
+    state: Arc<Mutex<PauseState>>,
+}
+
+impl PauseHandle {
+    pub fn pause(&self) {
+        self.state.lock().unwrap().paused = true;
+    }
+
+    pub fn resume(&self) {
+        let waker = {
+            let mut state = self.state.lock().unwrap();
+            state.paused = false;
+            state.waker.take()
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.state.lock().unwrap().paused
+    }
+}
+
+#[pin_project::pin_project]
+#[derive(Debug)]
+pub struct PausableStream<S> {
+    #[pin]
+    stream: S,
+    handle: PauseHandle,
+}
+
+impl<S: FusedStream> FusedStream for PausableStream<S> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<S: Stream> Stream for PausableStream<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let me = self.project();
+
+        {
+            let mut state = me.handle.state.lock().unwrap();
+            if state.paused {
+                state.waker = Some(cx.waker().clone());
+                return Poll::Pending;
+            }
+        }
+
+        me.stream.poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
@@ -0,0 +1,41 @@
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::FusedStream;
+use futures_core::stream::Stream;
+
+/// Creates a stream that never yields an item and never terminates.
+pub const fn pending<T>() -> Pending<T> {
+    Pending { p: PhantomData }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Pending<T> {
+    p: PhantomData<fn() -> T>,
+}
+
+impl<T> Default for Pending<T> {
+    fn default() -> Pending<T> {
+        pending()
+    }
+}
+
+impl<T> FusedStream for Pending<T> {
+    fn is_terminated(&self) -> bool {
+        false
+    }
+}
+
+impl<T> Stream for Pending<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
@@ -0,0 +1,90 @@
+use std::future::Future;
+use std::panic;
+use std::pin::pin;
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use crate::next;
+
+/// Drives `stream` on a spawned Tokio task, buffering up to `capacity` items ahead of the
+/// consumer.
+///
+/// The other adapters run their inner stream inline, only while they are polled; this one lets
+/// the producer make progress while the consumer is busy, at the cost of a task and a channel.
+/// The producer waits once the buffer is full, and is aborted when the stream is dropped.
+///
+/// # Panics
+///
+/// Panics if `capacity` is zero or if called outside a Tokio runtime, or resumes the panic if
+/// `stream` panics.
+pub fn prefetch<S>(stream: S, capacity: usize) -> impl Stream<Item = S::Item>
+where
+    S: Stream + Send + 'static,
+    S::Item: Send + 'static,
+{
+    assert!(capacity > 0, "capacity must be positive");
+    let (tx, rx) = mpsc::channel(capacity);
+    let task = tokio::spawn(async move {
+        let mut stream = pin!(stream);
+        while let Some(item) = next(stream.as_mut()).await {
+            if tx.send(item).await.is_err() {
+                break;
+            }
+        }
+    });
+    Prefetch {
+        rx,
+        task,
+        done: false,
+    }
+}
+
+struct Prefetch<T> {
+    rx: mpsc::Receiver<T>,
+    task: JoinHandle<()>,
+    done: bool,
+}
+
+impl<T> Drop for Prefetch<T> {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+impl<T> Stream for Prefetch<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let me = &mut *self;
+
+        if me.done {
+            return Poll::Ready(None);
+        }
+
+        if let Some(item) = ready!(me.rx.poll_recv(cx)) {
+            return Poll::Ready(Some(item));
+        }
+
+        // The producer has finished; surface its panic, if any.
+        let res = ready!(Pin::new(&mut me.task).poll(cx));
+        me.done = true;
+        match res {
+            Err(err) if err.is_panic() => panic::resume_unwind(err.into_panic()),
+            _ => Poll::Ready(None),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (self.rx.len(), None)
+        }
+    }
+}
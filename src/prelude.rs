@@ -0,0 +1,50 @@
+//! Everything needed to build and consume streams with a single import.
+//!
+//! ```
+//! use make_async_stream::prelude::*;
+//!
+//! fn evens() -> BoxStream<'static, u32> {
+//!     Box::pin(make_stream(async |tx| {
+//!         for i in 0..5 {
+//!             tx.send(i * 2).await;
+//!         }
+//!     }))
+//! }
+//!
+//! fn parsed(input: &'static str) -> BoxTryStream<'static, u32, std::num::ParseIntError> {
+//!     Box::pin(make_try_stream(async move |tx| {
+//!         for word in input.split(' ') {
+//!             tx.send(word.parse()?).await;
+//!         }
+//!         Ok(())
+//!     }))
+//! }
+//!
+//! futures::executor::block_on(async {
+//!     assert_eq!(evens().max().await, Some(8));
+//!
+//!     let mut numbers = parsed("1 2 x");
+//!     assert_eq!(numbers.try_next().await, Ok(Some(1)));
+//!     assert_eq!(numbers.try_next().await, Ok(Some(2)));
+//!     assert!(numbers.try_next().await.is_err());
+//!     assert_eq!(numbers.try_next().await, Ok(None));
+//! });
+//! ```
+
+use std::pin::Pin;
+
+pub use futures_core::stream::FusedStream;
+pub use futures_core::stream::Stream;
+
+pub use crate::make_stream;
+pub use crate::make_try_stream;
+pub use crate::AsyncStreamExt;
+pub use crate::OnFail;
+pub use crate::Sender;
+pub use crate::TrySender;
+
+/// A boxed stream that can be sent across threads.
+pub type BoxStream<'a, T> = Pin<Box<dyn Stream<Item = T> + Send + 'a>>;
+
+/// A boxed stream of `Result`s that can be sent across threads.
+pub type BoxTryStream<'a, T, E> = BoxStream<'a, Result<T, E>>;
@@ -0,0 +1,66 @@
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Batches up to `max` items that `stream` can produce without suspending.
+///
+/// # Panics
+///
+/// Panics if `max` is zero.
+pub fn ready_chunks<S: Stream>(stream: S, max: usize) -> impl Stream<Item = Vec<S::Item>> {
+    assert!(max > 0, "max must be positive");
+    ReadyChunks {
+        stream,
+        max,
+        done: false,
+    }
+}
+
+#[pin_project::pin_project]
+struct ReadyChunks<S> {
+    #[pin]
+    stream: S,
+    max: usize,
+    done: bool,
+}
+
+impl<S: Stream> Stream for ReadyChunks<S> {
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        if *me.done {
+            return Poll::Ready(None);
+        }
+
+        let mut batch = Vec::new();
+        while batch.len() < *me.max {
+            match me.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => batch.push(item),
+                Poll::Ready(None) => {
+                    *me.done = true;
+                    break;
+                }
+                Poll::Pending => break,
+            }
+        }
+
+        match (batch.is_empty(), *me.done) {
+            (false, _) => Poll::Ready(Some(batch)),
+            (true, true) => Poll::Ready(None),
+            (true, false) => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        let (lower, upper) = self.stream.size_hint();
+        (lower.min(1), upper)
+    }
+}
@@ -0,0 +1,36 @@
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::FusedStream;
+use futures_core::stream::Stream;
+
+/// Creates a stream that yields clones of `value` forever.
+pub fn repeat<T: Clone>(value: T) -> Repeat<T> {
+    Repeat { value }
+}
+
+#[derive(Debug, Clone)]
+pub struct Repeat<T> {
+    value: T,
+}
+
+impl<T> Unpin for Repeat<T> {}
+
+impl<T: Clone> FusedStream for Repeat<T> {
+    fn is_terminated(&self) -> bool {
+        false
+    }
+}
+
+impl<T: Clone> Stream for Repeat<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(Some(self.value.clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
@@ -0,0 +1,83 @@
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crate::pair;
+use crate::AsyncStream;
+use crate::Demand;
+use crate::Sender;
+
+/// Creates a stream whose generator can publish checkpoints through
+/// [`ResumableSender::checkpoint`].
+///
+/// The generator starts from `start`, and the checkpoint it returns is published once it
+/// finishes. [`CheckpointHandle::latest`] reports the most recent checkpoint, which the caller
+/// can persist and pass as `start` to a fresh stream to resume from there.
+///
+/// The generator only runs while the stream is polled, so a checkpoint published after a send
+/// isn't seen until the consumer asks for another item. Publishing each checkpoint right before
+/// its send means a resumed stream replays at most the last item.
+pub fn make_resumable_stream<T, Ck>(
+    start: Ck,
+    closure: impl AsyncFnMut(&mut ResumableSender<T, Ck>, Ck) -> Ck + 'static,
+) -> (
+    AsyncStream<T, impl Future<Output = ()>>,
+    CheckpointHandle<Ck>,
+)
+where
+    Ck: Clone + 'static,
+{
+    let handle = CheckpointHandle {
+        latest: Arc::new(Mutex::new(start.clone())),
+    };
+    let (sender, rx) = pair::<T>();
+    let mut tx = ResumableSender {
+        sender,
+        latest: handle.latest.clone(),
+    };
+    let mut closure = closure;
+    let stream = AsyncStream::new(rx, async move {
+        let end = closure.async_call_mut((&mut tx, start)).await;
+        tx.checkpoint(end);
+    });
+    (stream, handle)
+}
+
+/// The handle a [`make_resumable_stream`] generator yields items and publishes checkpoints
+/// through.
+#[derive(Debug)]
+pub struct ResumableSender<T, Ck> {
+    sender: Sender<T>,
+    latest: Arc<Mutex<Ck>>,
+}
+
+impl<T, Ck> ResumableSender<T, Ck> {
+    /// See [`Sender::send`].
+    pub fn send(&self, value: T) -> impl Future<Output = ()> + '_ {
+        self.sender.send(value)
+    }
+
+    /// See [`Sender::demand`].
+    pub fn demand(&self) -> Demand {
+        self.sender.demand()
+    }
+
+    /// Publishes `ck` as the latest checkpoint of the stream; see [`CheckpointHandle::latest`].
+    pub fn checkpoint(&self, ck: Ck) {
+        *self.latest.lock().unwrap() = ck;
+    }
+}
+
+/// Reports the checkpoints published by a [`make_resumable_stream`] generator.
+#[derive(Debug, Clone)]
+pub struct CheckpointHandle<Ck> {
+    latest: Arc<Mutex<Ck>>,
+}
+
+impl<Ck: Clone> CheckpointHandle<Ck> {
+    /// Returns the most recently published checkpoint, or the start checkpoint if none has been
+    /// published yet.
+    pub fn latest(&self) -> Ck {
+        self.latest.lock().unwrap().clone()
+    }
+}
@@ -0,0 +1,39 @@
+use std::pin::pin;
+
+use futures_core::stream::Stream;
+
+use crate::make_stream;
+use crate::next;
+
+/// Passes each item of `stream` through the fallible async function `f`, retrying it up to
+/// `max_retries` times per item.
+///
+/// An item whose last attempt still fails yields that `Err`, and the stream continues with the
+/// next item.
+///
+/// Each attempt borrows the item, which an adapter can't hold next to the attempt's future, so
+/// `stream` and `f` are driven by a generator instead. Like every generator, it must be
+/// `'static`, which keeps `f` from capturing the [`Sender`](crate::Sender) of an enclosing one.
+pub fn retry_map<S, F, U, E>(
+    stream: S,
+    max_retries: usize,
+    mut f: F,
+) -> impl Stream<Item = Result<U, E>>
+where
+    S: Stream + 'static,
+    F: AsyncFnMut(&S::Item) -> Result<U, E> + 'static,
+{
+    make_stream(async move |tx| {
+        let mut stream = pin!(stream);
+        while let Some(item) = next(stream.as_mut()).await {
+            let mut res = f.async_call_mut((&item,)).await;
+            for _ in 0..max_retries {
+                if res.is_ok() {
+                    break;
+                }
+                res = f.async_call_mut((&item,)).await;
+            }
+            tx.send(res).await;
+        }
+    })
+}
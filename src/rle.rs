@@ -0,0 +1,125 @@
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Collapses each run of equal adjacent items of `stream` into a `(value, count)` pair.
+pub fn rle_encode<S>(stream: S) -> impl Stream<Item = (S::Item, usize)>
+where
+    S: Stream,
+    S::Item: PartialEq,
+{
+    RleEncode {
+        stream,
+        run: None,
+        done: false,
+    }
+}
+
+/// Expands each `(value, count)` pair of `stream` into `count` copies of `value`; the inverse of
+/// [`rle_encode`].
+pub fn rle_decode<S, T>(stream: S) -> impl Stream<Item = T>
+where
+    S: Stream<Item = (T, usize)>,
+    T: Clone,
+{
+    RleDecode { stream, run: None }
+}
+
+#[pin_project::pin_project]
+struct RleEncode<S: Stream> {
+    #[pin]
+    stream: S,
+    run: Option<(S::Item, usize)>,
+    done: bool,
+}
+
+impl<S> Stream for RleEncode<S>
+where
+    S: Stream,
+    S::Item: PartialEq,
+{
+    type Item = (S::Item, usize);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        if *me.done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            let Some(item) = ready!(me.stream.as_mut().poll_next(cx)) else {
+                *me.done = true;
+                return Poll::Ready(me.run.take());
+            };
+
+            match me.run {
+                Some((value, count)) if *value == item => *count += 1,
+                _ => {
+                    if let Some(run) = me.run.replace((item, 1)) {
+                        return Poll::Ready(Some(run));
+                    }
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        let run = usize::from(self.run.is_some());
+        let (lower, upper) = self.stream.size_hint();
+        (
+            lower.saturating_add(run).min(1),
+            upper.and_then(|upper| upper.checked_add(run)),
+        )
+    }
+}
+
+#[pin_project::pin_project]
+struct RleDecode<S, T> {
+    #[pin]
+    stream: S,
+    run: Option<(T, usize)>,
+}
+
+impl<S, T> Stream for RleDecode<S, T>
+where
+    S: Stream<Item = (T, usize)>,
+    T: Clone,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        loop {
+            match me.run.take() {
+                Some((value, 0)) => drop(value),
+                Some((value, 1)) => return Poll::Ready(Some(value)),
+                Some((value, count)) => {
+                    *me.run = Some((value.clone(), count - 1));
+                    return Poll::Ready(Some(value));
+                }
+                None => match ready!(me.stream.as_mut().poll_next(cx)) {
+                    Some(run) => *me.run = Some(run),
+                    None => return Poll::Ready(None),
+                },
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let run = self.run.as_ref().map_or(0, |(_, count)| *count);
+        let upper = match self.stream.size_hint() {
+            (_, Some(0)) => Some(run),
+            _ => None,
+        };
+        (run, upper)
+    }
+}
@@ -0,0 +1,81 @@
+use std::cmp;
+use std::ops::Add;
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Yields the running sum of `stream` after each item.
+pub fn running_sum<S>(stream: S) -> impl Stream<Item = S::Item>
+where
+    S: Stream,
+    S::Item: Add<Output = S::Item> + Clone,
+{
+    Running::new(stream, |acc, item| acc + item)
+}
+
+/// Yields the running minimum of `stream` after each item.
+pub fn running_min<S>(stream: S) -> impl Stream<Item = S::Item>
+where
+    S: Stream,
+    S::Item: Ord + Clone,
+{
+    Running::new(stream, cmp::min)
+}
+
+/// Yields the running maximum of `stream` after each item.
+pub fn running_max<S>(stream: S) -> impl Stream<Item = S::Item>
+where
+    S: Stream,
+    S::Item: Ord + Clone,
+{
+    Running::new(stream, cmp::max)
+}
+
+#[pin_project::pin_project]
+struct Running<S: Stream, F> {
+    #[pin]
+    stream: S,
+    acc: Option<S::Item>,
+    f: F,
+}
+
+impl<S: Stream, F> Running<S, F> {
+    fn new(stream: S, f: F) -> Running<S, F> {
+        Running {
+            stream,
+            acc: None,
+            f,
+        }
+    }
+}
+
+impl<S, F> Stream for Running<S, F>
+where
+    S: Stream,
+    S::Item: Clone,
+    F: FnMut(S::Item, S::Item) -> S::Item,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let me = self.project();
+
+        let Some(item) = ready!(me.stream.poll_next(cx)) else {
+            return Poll::Ready(None);
+        };
+
+        let acc = match me.acc.take() {
+            Some(acc) => (me.f)(acc, item),
+            None => item,
+        };
+        *me.acc = Some(acc.clone());
+        Poll::Ready(Some(acc))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
@@ -0,0 +1,81 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+
+use futures_core::stream::Stream;
+use tokio::time::Instant;
+use tokio::time::Sleep;
+
+/// Drains `stream` continuously and, every `period`, yields the latest item that arrived since the
+/// previous tick.
+///
+/// Ticks are counted from the first poll, and ticks with no new item yield nothing. A final item
+/// that arrives before `stream` ends is yielded at the next tick.
+pub fn sample<S: Stream>(stream: S, period: Duration) -> impl Stream<Item = S::Item> {
+    Sample {
+        stream,
+        period,
+        sleep: None,
+        latest: None,
+        done: false,
+    }
+}
+
+#[pin_project::pin_project]
+struct Sample<S: Stream> {
+    #[pin]
+    stream: S,
+    period: Duration,
+    #[pin]
+    sleep: Option<Sleep>,
+    latest: Option<S::Item>,
+    done: bool,
+}
+
+impl<S: Stream> Stream for Sample<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        if me.sleep.is_none() {
+            me.sleep.set(Some(tokio::time::sleep(*me.period)));
+        }
+        let mut sleep = me.sleep.as_pin_mut().unwrap();
+
+        loop {
+            while !*me.done {
+                match me.stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(item)) => *me.latest = Some(item),
+                    Poll::Ready(None) => *me.done = true,
+                    Poll::Pending => break,
+                }
+            }
+
+            if *me.done && me.latest.is_none() {
+                return Poll::Ready(None);
+            }
+
+            ready!(sleep.as_mut().poll(cx));
+            let next = sleep.deadline() + *me.period;
+            sleep.as_mut().reset(next.max(Instant::now()));
+
+            if let Some(item) = me.latest.take() {
+                return Poll::Ready(Some(item));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let latest = usize::from(self.latest.is_some());
+        if self.done {
+            return (latest, Some(latest));
+        }
+
+        let upper = self.stream.size_hint().1;
+        (0, upper.and_then(|upper| upper.checked_add(latest)))
+    }
+}
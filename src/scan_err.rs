@@ -0,0 +1,65 @@
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Passes `Ok` items of `stream` through, transforming `Err` items with `f` using state
+/// accumulated from the preceding `Ok` items.
+///
+/// The state starts as `init` and is updated by `update` for every `Ok` item.
+pub fn scan_err<S, St, T, E1, E2, U, F>(
+    stream: S,
+    init: St,
+    update: U,
+    f: F,
+) -> impl Stream<Item = Result<T, E2>>
+where
+    S: Stream<Item = Result<T, E1>>,
+    U: FnMut(&mut St, &T),
+    F: FnMut(&mut St, E1) -> E2,
+{
+    ScanErr {
+        stream,
+        state: init,
+        update,
+        f,
+    }
+}
+
+#[pin_project::pin_project]
+struct ScanErr<S, St, U, F> {
+    #[pin]
+    stream: S,
+    state: St,
+    update: U,
+    f: F,
+}
+
+impl<S, St, T, E1, E2, U, F> Stream for ScanErr<S, St, U, F>
+where
+    S: Stream<Item = Result<T, E1>>,
+    U: FnMut(&mut St, &T),
+    F: FnMut(&mut St, E1) -> E2,
+{
+    type Item = Result<T, E2>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let me = self.project();
+
+        let item = match ready!(me.stream.poll_next(cx)) {
+            Some(Ok(item)) => {
+                (me.update)(me.state, &item);
+                Ok(item)
+            }
+            Some(Err(err)) => Err((me.f)(me.state, err)),
+            None => return Poll::Ready(None),
+        };
+        Poll::Ready(Some(item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
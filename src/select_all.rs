@@ -0,0 +1,96 @@
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+use crate::make_stream;
+use crate::Sender;
+
+/// Runs all `generators` at once, yielding each item together with the index of the generator
+/// that produced it.
+///
+/// Generators are polled round-robin and dropped as soon as they finish. The stream terminates
+/// once all of them have.
+pub fn select_all<T, G>(generators: impl IntoIterator<Item = G>) -> impl Stream<Item = (usize, T)>
+where
+    G: AsyncFnOnce(&mut Sender<T>) -> () + 'static,
+{
+    let streams = generators
+        .into_iter()
+        .map(|generator| Box::pin(make_stream(generator)))
+        .enumerate()
+        .collect();
+    SelectAll { streams, cursor: 0 }
+}
+
+struct SelectAll<S> {
+    streams: Vec<(usize, Pin<Box<S>>)>,
+    cursor: usize,
+}
+
+impl<S> Unpin for SelectAll<S> {}
+
+impl<S: Stream> SelectAll<S> {
+    /// Polls the streams in `i..end` until one yields an item.
+    fn poll_range(
+        &mut self,
+        cx: &mut Context<'_>,
+        mut i: usize,
+        mut end: usize,
+    ) -> Option<(usize, S::Item)> {
+        while i < end {
+            let (index, stream) = &mut self.streams[i];
+            match stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    self.cursor = i + 1;
+                    return Some((*index, item));
+                }
+                Poll::Ready(None) => {
+                    drop(self.streams.swap_remove(i));
+                    // The last stream takes over slot `i`. If it came from within the range, it
+                    // is still due and the range shrinks; otherwise it was already polled.
+                    if self.streams.len() < end {
+                        end = self.streams.len();
+                    } else {
+                        i += 1;
+                    }
+                }
+                Poll::Pending => i += 1,
+            }
+        }
+        None
+    }
+}
+
+impl<S: Stream> Stream for SelectAll<S> {
+    type Item = (usize, S::Item);
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let me = &mut *self;
+
+        // Poll every stream once, starting from the cursor and wrapping around.
+        let start = me.cursor.min(me.streams.len());
+        let end = me.streams.len();
+        if let Some(item) = me.poll_range(cx, start, end) {
+            return Poll::Ready(Some(item));
+        }
+        if let Some(item) = me.poll_range(cx, 0, start) {
+            return Poll::Ready(Some(item));
+        }
+
+        if me.streams.is_empty() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.streams.is_empty() {
+            (0, Some(0))
+        } else {
+            (0, None)
+        }
+    }
+}
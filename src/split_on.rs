@@ -0,0 +1,78 @@
+use std::mem;
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Splits `stream` into the groups of items separated by `delimiter`.
+///
+/// Delimiters are not included in the groups. Like [`str::split`], adjacent delimiters yield an
+/// empty group in between and a trailing delimiter yields a trailing empty group; an empty stream
+/// yields no groups at all.
+pub fn split_on<S>(stream: S, delimiter: S::Item) -> impl Stream<Item = Vec<S::Item>>
+where
+    S: Stream,
+    S::Item: PartialEq,
+{
+    SplitOn {
+        stream,
+        delimiter,
+        group: Vec::new(),
+        open: false,
+        done: false,
+    }
+}
+
+#[pin_project::pin_project]
+struct SplitOn<S: Stream> {
+    #[pin]
+    stream: S,
+    delimiter: S::Item,
+    group: Vec<S::Item>,
+    open: bool,
+    done: bool,
+}
+
+impl<S> Stream for SplitOn<S>
+where
+    S: Stream,
+    S::Item: PartialEq,
+{
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        if *me.done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            let Some(item) = ready!(me.stream.as_mut().poll_next(cx)) else {
+                *me.done = true;
+                let open = mem::replace(me.open, false);
+                return Poll::Ready(open.then(|| mem::take(me.group)));
+            };
+
+            *me.open = true;
+            if item == *me.delimiter {
+                return Poll::Ready(Some(mem::take(me.group)));
+            }
+            me.group.push(item);
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        let upper = self.stream.size_hint().1;
+        (
+            usize::from(self.open),
+            upper.and_then(|upper| upper.checked_add(1)),
+        )
+    }
+}
@@ -0,0 +1,34 @@
+use futures_core::stream::Stream;
+
+use crate::make_stream;
+
+/// The outcome of one invocation of a [`make_state_machine`] step function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Step<T> {
+    /// Yield an item, then invoke the step function again on the next poll.
+    Emit(T),
+    /// Yield nothing and invoke the step function again right away.
+    Skip,
+    /// Terminate the stream.
+    Done,
+}
+
+/// Creates a stream driven by repeatedly invoking `step` on a state initialized to `init`.
+pub fn make_state_machine<T, S>(
+    init: S,
+    mut step: impl AsyncFnMut(&mut S) -> Step<T> + 'static,
+) -> impl Stream<Item = T>
+where
+    S: 'static,
+{
+    make_stream(async move |tx| {
+        let mut state = init;
+        loop {
+            match step.async_call_mut((&mut state,)).await {
+                Step::Emit(item) => tx.send(item).await,
+                Step::Skip => {}
+                Step::Done => break,
+            }
+        }
+    })
+}
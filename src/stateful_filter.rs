@@ -0,0 +1,55 @@
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Yields the items of `stream` for which `pred` returns `true`.
+///
+/// `pred` also gets mutable access to a state starting at `init`, so it can decide based on the
+/// items seen so far.
+pub fn stateful_filter<S, St, F>(stream: S, init: St, pred: F) -> impl Stream<Item = S::Item>
+where
+    S: Stream,
+    F: FnMut(&mut St, &S::Item) -> bool,
+{
+    StatefulFilter {
+        stream,
+        state: init,
+        pred,
+    }
+}
+
+#[pin_project::pin_project]
+struct StatefulFilter<S, St, F> {
+    #[pin]
+    stream: S,
+    state: St,
+    pred: F,
+}
+
+impl<S, St, F> Stream for StatefulFilter<S, St, F>
+where
+    S: Stream,
+    F: FnMut(&mut St, &S::Item) -> bool,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        loop {
+            let Some(item) = ready!(me.stream.as_mut().poll_next(cx)) else {
+                return Poll::Ready(None);
+            };
+            if (me.pred)(me.state, &item) {
+                return Poll::Ready(Some(item));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.stream.size_hint().1)
+    }
+}
@@ -0,0 +1,68 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Passes each item of `stream` through the async function `f`, yielding its results in order.
+///
+/// Only one future returned by `f` runs at a time, and the next item isn't pulled until it
+/// completes.
+pub fn then<S, F, Fut>(stream: S, f: F) -> impl Stream<Item = Fut::Output>
+where
+    S: Stream,
+    F: FnMut(S::Item) -> Fut,
+    Fut: Future,
+{
+    Then {
+        stream,
+        f,
+        fut: None,
+    }
+}
+
+#[pin_project::pin_project]
+struct Then<S, F, Fut> {
+    #[pin]
+    stream: S,
+    f: F,
+    #[pin]
+    fut: Option<Fut>,
+}
+
+impl<S, F, Fut> Stream for Then<S, F, Fut>
+where
+    S: Stream,
+    F: FnMut(S::Item) -> Fut,
+    Fut: Future,
+{
+    type Item = Fut::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        loop {
+            if let Some(fut) = me.fut.as_mut().as_pin_mut() {
+                let output = ready!(fut.poll(cx));
+                me.fut.set(None);
+                return Poll::Ready(Some(output));
+            }
+
+            match ready!(me.stream.as_mut().poll_next(cx)) {
+                Some(item) => me.fut.set(Some((me.f)(item))),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = usize::from(self.fut.is_some());
+        let (lower, upper) = self.stream.size_hint();
+        (
+            lower.saturating_add(pending),
+            upper.and_then(|upper| upper.checked_add(pending)),
+        )
+    }
+}
@@ -0,0 +1,32 @@
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Instant;
+
+use futures_core::stream::Stream;
+
+/// Pairs each item of `stream` with the [`Instant`] it was yielded at.
+pub fn timestamped<S: Stream>(stream: S) -> impl Stream<Item = (S::Item, Instant)> {
+    Timestamped { stream }
+}
+
+#[pin_project::pin_project]
+struct Timestamped<S> {
+    #[pin]
+    stream: S,
+}
+
+impl<S: Stream> Stream for Timestamped<S> {
+    type Item = (S::Item, Instant);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project()
+            .stream
+            .poll_next(cx)
+            .map(|item| item.map(|item| (item, Instant::now())))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
@@ -0,0 +1,99 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Drives up to `n` futures from `stream` concurrently, yielding their `Ok` outputs as they
+/// complete.
+///
+/// The first `Err` is yielded and terminates the stream: futures still in flight are dropped
+/// without being polled again, and no further futures are pulled from `stream`.
+///
+/// # Panics
+///
+/// Panics if `n` is zero.
+pub fn try_buffered<S, T, E>(stream: S, n: usize) -> impl Stream<Item = Result<T, E>>
+where
+    S: Stream,
+    S::Item: Future<Output = Result<T, E>>,
+{
+    assert!(n > 0, "n must be positive");
+    TryBuffered {
+        stream,
+        n,
+        stream_done: false,
+        done: false,
+        in_flight: Vec::with_capacity(n),
+    }
+}
+
+#[pin_project::pin_project]
+struct TryBuffered<S: Stream> {
+    #[pin]
+    stream: S,
+    n: usize,
+    stream_done: bool,
+    done: bool,
+    in_flight: Vec<Pin<Box<S::Item>>>,
+}
+
+impl<S, T, E> Stream for TryBuffered<S>
+where
+    S: Stream,
+    S::Item: Future<Output = Result<T, E>>,
+{
+    type Item = Result<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        if *me.done {
+            return Poll::Ready(None);
+        }
+
+        while !*me.stream_done && me.in_flight.len() < *me.n {
+            match me.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(fut)) => me.in_flight.push(Box::pin(fut)),
+                Poll::Ready(None) => *me.stream_done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        for i in 0..me.in_flight.len() {
+            if let Poll::Ready(res) = me.in_flight[i].as_mut().poll(cx) {
+                drop(me.in_flight.swap_remove(i));
+                if res.is_err() {
+                    *me.done = true;
+                    me.in_flight.clear();
+                }
+                return Poll::Ready(Some(res));
+            }
+        }
+
+        if *me.stream_done && me.in_flight.is_empty() {
+            *me.done = true;
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        let in_flight = self.in_flight.len();
+        let upper = if self.stream_done {
+            Some(in_flight)
+        } else {
+            self.stream
+                .size_hint()
+                .1
+                .and_then(|upper| upper.checked_add(in_flight))
+        };
+        (0, upper)
+    }
+}
@@ -0,0 +1,86 @@
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Flattens a try-stream of try-streams, yielding the first outer or inner error and then
+/// terminating.
+pub fn try_flatten<S, U, T, E>(stream: S) -> impl Stream<Item = Result<T, E>>
+where
+    S: Stream<Item = Result<U, E>>,
+    U: Stream<Item = Result<T, E>>,
+{
+    TryFlatten {
+        stream,
+        sub: None,
+        done: false,
+    }
+}
+
+#[pin_project::pin_project]
+struct TryFlatten<S, U> {
+    #[pin]
+    stream: S,
+    #[pin]
+    sub: Option<U>,
+    done: bool,
+}
+
+impl<S, U, T, E> Stream for TryFlatten<S, U>
+where
+    S: Stream<Item = Result<U, E>>,
+    U: Stream<Item = Result<T, E>>,
+{
+    type Item = Result<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut me = self.project();
+
+        if *me.done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            if let Some(sub) = me.sub.as_mut().as_pin_mut() {
+                match ready!(sub.poll_next(cx)) {
+                    Some(Ok(item)) => return Poll::Ready(Some(Ok(item))),
+                    Some(Err(err)) => {
+                        *me.done = true;
+                        me.sub.set(None);
+                        return Poll::Ready(Some(Err(err)));
+                    }
+                    None => me.sub.set(None),
+                }
+            }
+
+            match ready!(me.stream.as_mut().poll_next(cx)) {
+                Some(Ok(sub)) => me.sub.set(Some(sub)),
+                Some(Err(err)) => {
+                    *me.done = true;
+                    return Poll::Ready(Some(Err(err)));
+                }
+                None => {
+                    *me.done = true;
+                    return Poll::Ready(None);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        let (sub_lower, sub_upper) = self
+            .sub
+            .as_ref()
+            .map_or((0, Some(0)), |sub| sub.size_hint());
+        match self.stream.size_hint() {
+            (_, Some(0)) => (sub_lower, sub_upper),
+            _ => (sub_lower, None),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::FusedStream;
+use futures_core::stream::Stream;
+
+use crate::make_stream;
+use crate::Sender;
+
+/// Like [`make_stream`], but calls `on_stuck` once more than `max_idle_polls` calls to
+/// `poll_next` in a row have returned `Pending`.
+///
+/// The count resets whenever an item is yielded or the stream terminates, so `on_stuck` fires at
+/// most once per stall. This is a diagnostics aid for spotting generators that stopped making
+/// progress; it does not interrupt them.
+pub fn make_watchdog_stream<T>(
+    max_idle_polls: usize,
+    on_stuck: impl FnMut(),
+    closure: impl AsyncFnOnce(&mut Sender<T>) -> () + 'static,
+) -> impl FusedStream<Item = T> {
+    Watchdog {
+        stream: make_stream(closure),
+        max_idle_polls,
+        idle_polls: 0,
+        on_stuck,
+    }
+}
+
+#[pin_project::pin_project]
+struct Watchdog<S, F> {
+    #[pin]
+    stream: S,
+    max_idle_polls: usize,
+    idle_polls: usize,
+    on_stuck: F,
+}
+
+impl<S: FusedStream, F: FnMut()> FusedStream for Watchdog<S, F> {
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+impl<S: Stream, F: FnMut()> Stream for Watchdog<S, F> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let me = self.project();
+
+        let res = me.stream.poll_next(cx);
+        if res.is_pending() {
+            *me.idle_polls = me.idle_polls.saturating_add(1);
+            if me.max_idle_polls.checked_add(1) == Some(*me.idle_polls) {
+                (me.on_stuck)();
+            }
+        } else {
+            *me.idle_polls = 0;
+        }
+        res
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
@@ -0,0 +1,74 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::ready;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Yields the items of `stream` until `signal` resolves, then terminates.
+///
+/// `signal` is checked before each pull of an item. Once it has resolved, no new item is pulled:
+/// `stream` is only polled again if its `size_hint` promises an item, which for an
+/// [`AsyncStream`](crate::AsyncStream) means one buffered through
+/// [`AsyncStream::poll_progress`](crate::AsyncStream::poll_progress). That item is delivered
+/// without resuming the generator, and the stream then terminates.
+pub fn with_shutdown<S, F>(stream: S, signal: F) -> impl Stream<Item = S::Item>
+where
+    S: Stream,
+    F: Future<Output = ()>,
+{
+    WithShutdown {
+        stream,
+        signal,
+        done: false,
+    }
+}
+
+#[pin_project::pin_project]
+struct WithShutdown<S, F> {
+    #[pin]
+    stream: S,
+    #[pin]
+    signal: F,
+    done: bool,
+}
+
+impl<S, F> Stream for WithShutdown<S, F>
+where
+    S: Stream,
+    F: Future<Output = ()>,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let me = self.project();
+
+        if *me.done {
+            return Poll::Ready(None);
+        }
+
+        if me.signal.poll(cx).is_ready() {
+            *me.done = true;
+            if me.stream.size_hint().0 == 0 {
+                return Poll::Ready(None);
+            }
+            return match me.stream.poll_next(cx) {
+                Poll::Ready(item) => Poll::Ready(item),
+                Poll::Pending => Poll::Ready(None),
+            };
+        }
+
+        let item = ready!(me.stream.poll_next(cx));
+        *me.done = item.is_none();
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        (0, self.stream.size_hint().1)
+    }
+}
@@ -0,0 +1,27 @@
+use std::cell::RefCell;
+use std::future::Future;
+use std::rc::Rc;
+
+use crate::pair;
+use crate::AsyncStream;
+use crate::Sender;
+
+/// Like [`make_stream`](crate::make_stream), but the generator and the caller share `state`.
+///
+/// The generator runs while the stream is polled, so the caller must not hold a borrow of the
+/// state across a poll, or the generator's own borrow panics.
+pub fn make_stream_with_state<T, S>(
+    state: S,
+    closure: impl AsyncFnOnce(&mut Sender<T>, Rc<RefCell<S>>) -> () + 'static,
+) -> (AsyncStream<T, impl Future<Output = ()>>, Rc<RefCell<S>>)
+where
+    S: 'static,
+{
+    let state = Rc::new(RefCell::new(state));
+    let shared = state.clone();
+    let (mut tx, rx) = pair::<T>();
+    let stream = AsyncStream::new(rx, async move {
+        closure.async_call_once((&mut tx, shared)).await;
+    });
+    (stream, state)
+}
@@ -0,0 +1,119 @@
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Pairs up items of `a` and `b`, terminating as soon as either is exhausted.
+pub fn zip<A, B>(a: A, b: B) -> impl Stream<Item = (A::Item, B::Item)>
+where
+    A: Stream,
+    B: Stream,
+{
+    Zip::new(a, b, |a, b| (a, b))
+}
+
+/// Combines paired items of `a` and `b` with `f`, terminating as soon as either is exhausted.
+pub fn zip_with<A, B, F, C>(a: A, b: B, f: F) -> impl Stream<Item = C>
+where
+    A: Stream,
+    B: Stream,
+    F: FnMut(A::Item, B::Item) -> C,
+{
+    Zip::new(a, b, f)
+}
+
+#[pin_project::pin_project]
+struct Zip<A: Stream, B: Stream, F> {
+    #[pin]
+    a: A,
+    #[pin]
+    b: B,
+    queued_a: Option<A::Item>,
+    queued_b: Option<B::Item>,
+    f: F,
+    done: bool,
+}
+
+impl<A: Stream, B: Stream, F> Zip<A, B, F> {
+    fn new(a: A, b: B, f: F) -> Zip<A, B, F> {
+        Zip {
+            a,
+            b,
+            queued_a: None,
+            queued_b: None,
+            f,
+            done: false,
+        }
+    }
+}
+
+impl<A, B, F, C> Stream for Zip<A, B, F>
+where
+    A: Stream,
+    B: Stream,
+    F: FnMut(A::Item, B::Item) -> C,
+{
+    type Item = C;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let me = self.project();
+
+        if *me.done {
+            return Poll::Ready(None);
+        }
+
+        if me.queued_a.is_none() {
+            match me.a.poll_next(cx) {
+                Poll::Ready(Some(item)) => *me.queued_a = Some(item),
+                Poll::Ready(None) => *me.done = true,
+                Poll::Pending => {}
+            }
+        }
+
+        if !*me.done && me.queued_b.is_none() {
+            match me.b.poll_next(cx) {
+                Poll::Ready(Some(item)) => *me.queued_b = Some(item),
+                Poll::Ready(None) => *me.done = true,
+                Poll::Pending => {}
+            }
+        }
+
+        if *me.done {
+            *me.queued_a = None;
+            *me.queued_b = None;
+            return Poll::Ready(None);
+        }
+
+        match (me.queued_a.take(), me.queued_b.take()) {
+            (Some(a), Some(b)) => Poll::Ready(Some((me.f)(a, b))),
+            (a, b) => {
+                *me.queued_a = a;
+                *me.queued_b = b;
+                Poll::Pending
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        let queued_a = usize::from(self.queued_a.is_some());
+        let queued_b = usize::from(self.queued_b.is_some());
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        let a_upper = a_upper.and_then(|upper| upper.checked_add(queued_a));
+        let b_upper = b_upper.and_then(|upper| upper.checked_add(queued_b));
+
+        let lower = a_lower
+            .saturating_add(queued_a)
+            .min(b_lower.saturating_add(queued_b));
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (upper, None) | (None, upper) => upper,
+        };
+        (lower, upper)
+    }
+}
@@ -0,0 +1,93 @@
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use futures_core::stream::Stream;
+
+/// Pairs up items of `a` and `b` until both are exhausted.
+///
+/// Once one stream has finished, the other's remaining items are paired with `None`. The stream
+/// ends right after the last item of the longer stream, without a trailing `(None, None)`.
+pub fn zip_longest<A, B>(a: A, b: B) -> impl Stream<Item = (Option<A::Item>, Option<B::Item>)>
+where
+    A: Stream,
+    B: Stream,
+{
+    ZipLongest {
+        a,
+        b,
+        queued_a: None,
+        queued_b: None,
+        a_done: false,
+        b_done: false,
+    }
+}
+
+#[pin_project::pin_project]
+struct ZipLongest<A: Stream, B: Stream> {
+    #[pin]
+    a: A,
+    #[pin]
+    b: B,
+    queued_a: Option<A::Item>,
+    queued_b: Option<B::Item>,
+    a_done: bool,
+    b_done: bool,
+}
+
+impl<A: Stream, B: Stream> Stream for ZipLongest<A, B> {
+    type Item = (Option<A::Item>, Option<B::Item>);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let me = self.project();
+
+        if !*me.a_done && me.queued_a.is_none() {
+            match me.a.poll_next(cx) {
+                Poll::Ready(Some(item)) => *me.queued_a = Some(item),
+                Poll::Ready(None) => *me.a_done = true,
+                Poll::Pending => {}
+            }
+        }
+
+        if !*me.b_done && me.queued_b.is_none() {
+            match me.b.poll_next(cx) {
+                Poll::Ready(Some(item)) => *me.queued_b = Some(item),
+                Poll::Ready(None) => *me.b_done = true,
+                Poll::Pending => {}
+            }
+        }
+
+        let a_settled = *me.a_done || me.queued_a.is_some();
+        let b_settled = *me.b_done || me.queued_b.is_some();
+        if !a_settled || !b_settled {
+            return Poll::Pending;
+        }
+
+        match (me.queued_a.take(), me.queued_b.take()) {
+            (None, None) => Poll::Ready(None),
+            pair => Poll::Ready(Some(pair)),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let side = |done: bool, queued: bool, (lower, upper): (usize, Option<usize>)| {
+            let queued = usize::from(queued);
+            if done {
+                (queued, Some(queued))
+            } else {
+                (
+                    lower.saturating_add(queued),
+                    upper.and_then(|upper| upper.checked_add(queued)),
+                )
+            }
+        };
+        let (a_lower, a_upper) = side(self.a_done, self.queued_a.is_some(), self.a.size_hint());
+        let (b_lower, b_upper) = side(self.b_done, self.queued_b.is_some(), self.b.size_hint());
+
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            _ => None,
+        };
+        (a_lower.max(b_lower), upper)
+    }
+}
@@ -0,0 +1,24 @@
+use futures::StreamExt;
+use make_async_stream::accumulate;
+use make_async_stream::empty;
+use make_async_stream::make_stream;
+
+#[tokio::test]
+async fn accumulate_emits_seed_and_running_values() {
+    let numbers = make_stream(async move |tx| {
+        for i in [1, 2, 3] {
+            tx.send(i).await;
+        }
+    });
+
+    let items: Vec<i32> = accumulate(numbers, 0, |acc, i| acc + i).collect().await;
+    assert_eq!(items, vec![0, 1, 3, 6]);
+}
+
+#[tokio::test]
+async fn accumulate_of_empty_stream_emits_seed() {
+    let items: Vec<i32> = accumulate(empty::<i32>(), 42, |acc, i| acc + i)
+        .collect()
+        .await;
+    assert_eq!(items, vec![42]);
+}
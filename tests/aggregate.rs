@@ -0,0 +1,69 @@
+use futures::Stream;
+use make_async_stream::empty;
+use make_async_stream::make_stream;
+use make_async_stream::AsyncStreamExt;
+
+fn numbers() -> impl Stream<Item = i32> {
+    make_stream(async move |tx| {
+        for i in [3, 1, 4, 1, 5, 9, 2, 6] {
+            tx.send(i).await;
+        }
+    })
+}
+
+#[tokio::test]
+async fn sum_drains_stream() {
+    assert_eq!(numbers().sum::<i32>().await, 31);
+    assert_eq!(empty::<i32>().sum::<i32>().await, 0);
+}
+
+#[tokio::test]
+async fn sum_of_options_is_none_if_any_item_is_none() {
+    let results = make_stream(async move |tx| {
+        tx.send(Some(1)).await;
+        tx.send(None).await;
+        tx.send(Some(2)).await;
+    });
+    assert_eq!(results.sum::<Option<i32>>().await, None);
+}
+
+#[tokio::test]
+async fn min_and_max_drain_stream() {
+    assert_eq!(numbers().min().await, Some(1));
+    assert_eq!(numbers().max().await, Some(9));
+}
+
+#[tokio::test]
+async fn min_and_max_of_empty_stream_are_none() {
+    assert_eq!(empty::<i32>().min().await, None);
+    assert_eq!(empty::<i32>().max().await, None);
+}
+
+#[tokio::test]
+async fn min_and_max_break_ties_like_iterators() {
+    let pairs = || {
+        make_stream(async move |tx| {
+            for pair in [(1, 'a'), (2, 'b'), (1, 'c'), (2, 'd')] {
+                tx.send(Key(pair)).await;
+            }
+        })
+    };
+
+    assert_eq!(pairs().min().await, Some(Key((1, 'a'))));
+    assert_eq!(pairs().max().await, Some(Key((2, 'd'))));
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Key((i32, char));
+
+impl PartialOrd for Key {
+    fn partial_cmp(&self, other: &Key) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Key {
+    fn cmp(&self, other: &Key) -> std::cmp::Ordering {
+        self.0 .0.cmp(&other.0 .0)
+    }
+}
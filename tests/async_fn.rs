@@ -0,0 +1,28 @@
+use futures::StreamExt;
+use make_async_stream::make_stream;
+use make_async_stream::make_try_stream;
+use make_async_stream::Sender;
+use make_async_stream::TrySender;
+
+async fn count_to_three(tx: &mut Sender<i32>) {
+    for i in 1..=3 {
+        tx.send(i).await;
+    }
+}
+
+async fn fail_after_one(tx: &mut TrySender<i32, String>) -> Result<(), String> {
+    tx.send(1).await;
+    Err("boom".to_string())
+}
+
+#[tokio::test]
+async fn make_stream_accepts_async_fn() {
+    let items: Vec<i32> = make_stream(count_to_three).collect().await;
+    assert_eq!(items, vec![1, 2, 3]);
+}
+
+#[tokio::test]
+async fn make_try_stream_accepts_async_fn() {
+    let items: Vec<Result<i32, String>> = make_try_stream(fail_after_one).collect().await;
+    assert_eq!(items, vec![Ok(1), Err("boom".to_string())]);
+}
@@ -0,0 +1,34 @@
+#![cfg(feature = "tokio")]
+
+use std::time::Duration;
+
+use futures::StreamExt;
+use make_async_stream::batch;
+use make_async_stream::make_stream;
+
+#[tokio::test(start_paused = true)]
+async fn batch_flushes_on_size_when_items_arrive_quickly() {
+    let numbers = make_stream(async move |tx| {
+        for i in 0..7 {
+            tx.send(i).await;
+        }
+    });
+
+    let batches: Vec<Vec<u32>> = batch(numbers, 3, Duration::from_secs(1)).collect().await;
+    assert_eq!(batches, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn batch_flushes_on_timer_when_items_trickle_in() {
+    let numbers = make_stream(async move |tx| {
+        for i in 0..4 {
+            tx.send(i).await;
+            tokio::time::sleep(Duration::from_millis(40)).await;
+        }
+    });
+
+    let batches: Vec<Vec<u32>> = batch(numbers, 10, Duration::from_millis(100))
+        .collect()
+        .await;
+    assert_eq!(batches, vec![vec![0, 1, 2], vec![3]]);
+}
@@ -0,0 +1,34 @@
+use futures::StreamExt;
+use make_async_stream::bounded_count;
+use make_async_stream::make_stream;
+
+#[tokio::test]
+async fn bounded_count_surfaces_overflow() {
+    let runaway = make_stream(async move |tx| {
+        for i in 0.. {
+            tx.send(i).await;
+        }
+    });
+
+    let items: Vec<Result<u32, String>> =
+        bounded_count(runaway, 3, || "too many items".to_string())
+            .collect()
+            .await;
+    assert_eq!(
+        items,
+        vec![Ok(0), Ok(1), Ok(2), Err("too many items".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn bounded_count_passes_streams_within_cap() {
+    let numbers = make_stream(async move |tx| {
+        for i in 0..3 {
+            tx.send(i).await;
+        }
+    });
+
+    let items: Vec<Result<u32, String>> =
+        bounded_count(numbers, 3, || unreachable!()).collect().await;
+    assert_eq!(items, vec![Ok(0), Ok(1), Ok(2)]);
+}
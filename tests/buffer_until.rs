@@ -0,0 +1,20 @@
+use futures::StreamExt;
+use make_async_stream::buffer_until;
+use make_async_stream::make_stream;
+
+#[tokio::test]
+async fn buffer_until_flushes_when_sum_exceeds_threshold() {
+    let numbers = make_stream(async move |tx| {
+        for i in [4, 3, 5, 1, 1, 9, 2, 2] {
+            tx.send(i).await;
+        }
+    });
+
+    let batches: Vec<Vec<i32>> = buffer_until(numbers, |buffer| buffer.iter().sum::<i32>() > 6)
+        .collect()
+        .await;
+    assert_eq!(
+        batches,
+        vec![vec![4, 3], vec![5, 1, 1], vec![9], vec![2, 2]]
+    );
+}
@@ -0,0 +1,29 @@
+use futures::StreamExt;
+use make_async_stream::catch;
+use make_async_stream::make_try_stream;
+
+#[tokio::test]
+async fn catch_recovers_into_fallback_value() {
+    let stream = make_try_stream(async move |tx| {
+        tx.send(1).await;
+        tx.send(2).await;
+        Err("unavailable".to_string())
+    });
+
+    let items: Vec<Result<i32, String>> = catch(stream, |_| Some(0)).collect().await;
+    assert_eq!(items, vec![Ok(1), Ok(2), Ok(0)]);
+}
+
+#[tokio::test]
+async fn catch_reemits_unrecovered_error() {
+    let stream = make_try_stream(async move |tx| {
+        tx.send(1).await;
+        Err("fatal".to_string())
+    });
+
+    let items: Vec<Result<i32, String>> =
+        catch(stream, |err: &String| (err != "fatal").then_some(0))
+            .collect()
+            .await;
+    assert_eq!(items, vec![Ok(1), Err("fatal".to_string())]);
+}
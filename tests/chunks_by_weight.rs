@@ -0,0 +1,36 @@
+use std::future;
+use std::pin::pin;
+use std::task::Poll;
+
+use futures::StreamExt;
+use make_async_stream::chunks_by_weight;
+use make_async_stream::make_stream;
+
+#[tokio::test]
+async fn chunks_by_weight_respects_budget() {
+    let items = make_stream(async move |tx| {
+        for i in [3, 4, 2, 5, 12, 1, 9, 1] {
+            tx.send(i).await;
+        }
+    });
+
+    let batches: Vec<Vec<usize>> = chunks_by_weight(items, 10, |i| *i).collect().await;
+    assert_eq!(
+        batches,
+        vec![vec![3, 4, 2], vec![5], vec![12], vec![1, 9], vec![1]]
+    );
+}
+
+#[tokio::test]
+async fn chunks_by_weight_flushes_overweight_item_without_waiting() {
+    let items = make_stream(async move |tx| {
+        tx.send(3).await;
+        tx.send(12).await;
+        future::pending::<()>().await;
+    });
+    let mut batches = pin!(chunks_by_weight(items, 10, |i| *i));
+
+    assert_eq!(batches.next().await, Some(vec![3]));
+    assert_eq!(futures::poll!(batches.next()), Poll::Ready(Some(vec![12])));
+    assert_eq!(futures::poll!(batches.next()), Poll::Pending);
+}
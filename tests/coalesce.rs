@@ -0,0 +1,23 @@
+use futures::StreamExt;
+use make_async_stream::coalesce;
+use make_async_stream::make_stream;
+
+#[tokio::test]
+async fn coalesce_counts_runs() {
+    let numbers = make_stream(async move |tx| {
+        for i in [1, 1, 2, 3, 3, 3, 1] {
+            tx.send((i, 1)).await;
+        }
+    });
+
+    let runs: Vec<(i32, usize)> = coalesce(numbers, |a, b| {
+        if a.0 == b.0 {
+            Ok((a.0, a.1 + b.1))
+        } else {
+            Err((a, b))
+        }
+    })
+    .collect()
+    .await;
+    assert_eq!(runs, vec![(1, 2), (2, 1), (3, 3), (1, 1)]);
+}
@@ -0,0 +1,33 @@
+use futures::stream;
+use futures::StreamExt;
+use make_async_stream::coalesce_by_key;
+
+#[derive(Debug, PartialEq)]
+struct Update {
+    entity: &'static str,
+    version: u32,
+}
+
+fn update(entity: &'static str, version: u32) -> Update {
+    Update { entity, version }
+}
+
+#[tokio::test]
+async fn coalesce_by_key_keeps_latest_update_of_each_run() {
+    let updates = stream::iter([
+        update("a", 1),
+        update("a", 2),
+        update("b", 1),
+        update("a", 3),
+        update("a", 4),
+        update("a", 5),
+    ]);
+
+    let compacted: Vec<Update> = coalesce_by_key(updates, |u| u.entity, |_, latest| latest)
+        .collect()
+        .await;
+    assert_eq!(
+        compacted,
+        vec![update("a", 2), update("b", 1), update("a", 5)]
+    );
+}
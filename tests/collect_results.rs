@@ -0,0 +1,11 @@
+use futures::stream;
+use make_async_stream::AsyncStreamExt;
+
+#[tokio::test]
+async fn collect_results_separates_oks_and_errors() {
+    let parsed = stream::iter(["1", "x", "2", "y", "3"].map(str::parse::<u32>));
+
+    let (oks, errs): (Vec<u32>, Vec<_>) = parsed.collect_results().await;
+    assert_eq!(oks, vec![1, 2, 3]);
+    assert_eq!(errs.len(), 2);
+}
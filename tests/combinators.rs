@@ -0,0 +1,94 @@
+use futures::stream;
+use futures::StreamExt;
+use make_async_stream::chain;
+use make_async_stream::make_stream;
+use make_async_stream::merge;
+use make_async_stream::zip;
+use make_async_stream::zip_with;
+
+#[tokio::test]
+async fn chain_concatenates() {
+    let a = make_stream(async move |tx| {
+        tx.send(1).await;
+        tx.send(2).await;
+    });
+    let b = make_stream(async move |tx| {
+        tx.send(3).await;
+    });
+
+    let items: Vec<i32> = chain(a, b).collect().await;
+    assert_eq!(items, vec![1, 2, 3]);
+}
+
+#[tokio::test]
+async fn zip_stops_at_shorter_stream() {
+    let a = make_stream(async move |tx| {
+        for i in 0..3 {
+            tx.send(i).await;
+        }
+    });
+    let b = make_stream(async move |tx| {
+        for c in ['a', 'b', 'c', 'd'] {
+            tx.send(c).await;
+        }
+    });
+
+    let items: Vec<(i32, char)> = zip(a, b).collect().await;
+    assert_eq!(items, vec![(0, 'a'), (1, 'b'), (2, 'c')]);
+}
+
+#[tokio::test]
+async fn zip_with_combines_pairs() {
+    let a = make_stream(async move |tx| {
+        for i in 0..5 {
+            tx.send(i).await;
+        }
+    });
+    let b = make_stream(async move |tx| {
+        for i in 10..20 {
+            tx.send(i).await;
+        }
+    });
+
+    let items: Vec<i32> = zip_with(a, b, |a, b| a + b).collect().await;
+    assert_eq!(items, vec![10, 12, 14, 16, 18]);
+}
+
+#[tokio::test]
+async fn merge_interleaves_until_both_end() {
+    let a = make_stream(async move |tx| {
+        for i in 0..3 {
+            tx.send(i).await;
+        }
+    });
+    let b = make_stream(async move |tx| {
+        for i in 10..15 {
+            tx.send(i).await;
+        }
+    });
+
+    let items: Vec<i32> = merge(a, b).collect().await;
+    assert_eq!(items, vec![10, 0, 11, 1, 12, 2, 13, 14]);
+}
+
+#[tokio::test]
+async fn combinators_accept_borrowed_streams() {
+    let left = vec![1, 2, 3];
+    let right = vec![10, 20, 30];
+
+    let chained: Vec<&i32> = chain(stream::iter(&left), stream::iter(&right))
+        .collect()
+        .await;
+    assert_eq!(chained, vec![&1, &2, &3, &10, &20, &30]);
+
+    let zipped: Vec<(&i32, &i32)> = zip(stream::iter(&left), stream::iter(&right))
+        .collect()
+        .await;
+    assert_eq!(zipped, vec![(&1, &10), (&2, &20), (&3, &30)]);
+
+    let mut merged: Vec<&i32> = merge(stream::iter(&left), stream::iter(&right))
+        .collect()
+        .await;
+    merged.sort();
+    assert_eq!(merged, vec![&1, &2, &3, &10, &20, &30]);
+}
@@ -0,0 +1,58 @@
+use std::pin::pin;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use futures::task::ArcWake;
+use futures::Stream;
+use futures::StreamExt;
+use make_async_stream::make_cooperative_stream;
+
+#[derive(Default)]
+struct CountingWaker(AtomicUsize);
+
+impl ArcWake for CountingWaker {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn cooperative_stream_yields_after_budget() {
+    let mut stream = pin!(make_cooperative_stream(3, async move |tx| {
+        for i in 0..5 {
+            tx.send(i).await;
+        }
+    }));
+    let wakes = Arc::new(CountingWaker::default());
+    let waker = futures::task::waker(wakes.clone());
+    let mut cx = Context::from_waker(&waker);
+
+    let mut polls = Vec::new();
+    loop {
+        match stream.as_mut().poll_next(&mut cx) {
+            Poll::Ready(Some(item)) => polls.push(Some(item)),
+            Poll::Ready(None) => break,
+            Poll::Pending => polls.push(None),
+        }
+    }
+    assert_eq!(
+        polls,
+        vec![Some(0), Some(1), Some(2), None, Some(3), Some(4)]
+    );
+    assert_eq!(wakes.0.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn cooperative_stream_wakes_itself() {
+    let items: Vec<u32> = make_cooperative_stream(2, async move |tx| {
+        for i in 0..7 {
+            tx.send(i).await;
+        }
+    })
+    .collect()
+    .await;
+    assert_eq!(items, (0..7).collect::<Vec<_>>());
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "tokio")]
+
+use std::time::Duration;
+
+use futures::StreamExt;
+use make_async_stream::debounce;
+use make_async_stream::make_stream;
+
+#[tokio::test(start_paused = true)]
+async fn debounce_emits_last_item_of_each_burst() {
+    let bursts = make_stream(async move |tx| {
+        for burst in [1..4, 4..6] {
+            for i in burst {
+                tx.send(i).await;
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    });
+
+    let items: Vec<i32> = debounce(bursts, Duration::from_millis(20)).collect().await;
+    assert_eq!(items, vec![3, 5]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn debounce_flushes_pending_item_on_termination() {
+    let burst = make_stream(async move |tx| {
+        for i in 1..4 {
+            tx.send(i).await;
+        }
+    });
+
+    let items: Vec<i32> = debounce(burst, Duration::from_secs(60)).collect().await;
+    assert_eq!(items, vec![3]);
+}
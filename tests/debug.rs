@@ -0,0 +1,24 @@
+use std::pin::pin;
+
+use futures::StreamExt;
+use make_async_stream::make_stream;
+
+#[tokio::test]
+async fn debug_reports_stream_state() {
+    let stream = make_stream(async move |tx| {
+        tx.send(1).await;
+    });
+    let mut stream = pin!(stream);
+
+    let debug = format!("{stream:?}");
+    assert!(debug.contains("done: false"), "{debug}");
+    assert!(debug.contains("buffered: false"), "{debug}");
+    assert!(debug.contains("size_hint: (0, None)"), "{debug}");
+
+    assert_eq!(stream.next().await, Some(1));
+    assert_eq!(stream.next().await, None);
+
+    let debug = format!("{stream:?}");
+    assert!(debug.contains("done: true"), "{debug}");
+    assert!(debug.contains("size_hint: (0, Some(0))"), "{debug}");
+}
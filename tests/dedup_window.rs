@@ -0,0 +1,15 @@
+use futures::StreamExt;
+use make_async_stream::dedup_window;
+use make_async_stream::make_stream;
+
+#[tokio::test]
+async fn dedup_window_suppresses_recent_items() {
+    let events = make_stream(async move |tx| {
+        for i in [1, 2, 1, 3, 2, 1] {
+            tx.send(i).await;
+        }
+    });
+
+    let items: Vec<i32> = dedup_window(events, 2).collect().await;
+    assert_eq!(items, vec![1, 2, 3, 1]);
+}
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use futures::StreamExt;
+use make_async_stream::make_stream;
+use make_async_stream::Demand;
+
+#[tokio::test]
+async fn demand_reports_backpressure_when_consumer_lags() {
+    let demands = Arc::new(Mutex::new(vec![]));
+
+    let recorded = demands.clone();
+    let stream = make_stream(async move |tx| {
+        tx.send(0).await;
+        recorded.lock().unwrap().push(tx.demand());
+
+        // The consumer takes one item per poll, so the second racing send has to wait.
+        let first = tx.send(1);
+        let second = tx.send(2);
+        futures::join!(first, second);
+        recorded.lock().unwrap().push(tx.demand());
+
+        tx.send(3).await;
+        recorded.lock().unwrap().push(tx.demand());
+    });
+
+    let items: Vec<i32> = stream.collect().await;
+    assert_eq!(items, vec![0, 1, 2, 3]);
+    assert_eq!(
+        *demands.lock().unwrap(),
+        vec![
+            Demand::Ready,
+            Demand::Backpressure { waited_polls: 1 },
+            Demand::Ready,
+        ]
+    );
+}
@@ -0,0 +1,8 @@
+use futures::stream;
+use make_async_stream::AsyncStreamExt;
+
+#[tokio::test]
+async fn distinct_keeps_first_occurrences_in_order() {
+    let items: Vec<i32> = stream::iter([3, 1, 3, 2, 1]).distinct().await;
+    assert_eq!(items, vec![3, 1, 2]);
+}
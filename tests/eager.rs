@@ -0,0 +1,31 @@
+use std::pin::pin;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use futures::Stream;
+use futures::StreamExt;
+use make_async_stream::make_eager_stream;
+
+#[tokio::test]
+async fn eager_stream_buffers_ahead() {
+    let produced = Arc::new(AtomicUsize::new(0));
+    let counter = produced.clone();
+    let stream = make_eager_stream(3, async move |tx| {
+        for i in 0..10 {
+            counter.fetch_add(1, Ordering::SeqCst);
+            tx.send(i).await;
+        }
+    });
+    let mut stream = pin!(stream);
+
+    assert_eq!(stream.next().await, Some(0));
+    assert_eq!(produced.load(Ordering::SeqCst), 4);
+    assert_eq!(stream.size_hint(), (3, None));
+
+    assert_eq!(stream.next().await, Some(1));
+    assert_eq!(produced.load(Ordering::SeqCst), 5);
+
+    let rest: Vec<i32> = stream.collect().await;
+    assert_eq!(rest, (2..10).collect::<Vec<_>>());
+}
@@ -0,0 +1,25 @@
+use futures::stream::FusedStream;
+use futures::Stream;
+use futures::StreamExt;
+use make_async_stream::empty;
+use make_async_stream::Empty;
+
+const NOTHING: Empty<i32> = empty();
+
+#[tokio::test]
+async fn empty_is_terminated_from_the_start() {
+    let mut stream = empty::<i32>();
+    assert!(stream.is_terminated());
+    assert_eq!(stream.size_hint(), (0, Some(0)));
+    assert_eq!(stream.next().await, None);
+    assert!(stream.is_terminated());
+}
+
+#[tokio::test]
+async fn empty_is_usable_as_const_and_default() {
+    let items: Vec<i32> = NOTHING.collect().await;
+    assert!(items.is_empty());
+
+    let items: Vec<i32> = Empty::<i32>::default().collect().await;
+    assert!(items.is_empty());
+}
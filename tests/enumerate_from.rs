@@ -0,0 +1,16 @@
+use futures::StreamExt;
+use make_async_stream::enumerate_from;
+use make_async_stream::make_stream;
+
+#[tokio::test]
+async fn enumerate_from_counts_from_start() {
+    let pages = make_stream(async move |tx| {
+        for page in ["a", "b", "c"] {
+            tokio::task::yield_now().await;
+            tx.send(page).await;
+        }
+    });
+
+    let items: Vec<(usize, &str)> = enumerate_from(pages, 100).collect().await;
+    assert_eq!(items, vec![(100, "a"), (101, "b"), (102, "c")]);
+}
@@ -0,0 +1,36 @@
+use futures::StreamExt;
+use make_async_stream::expand;
+use make_async_stream::make_stream;
+
+#[tokio::test]
+async fn expand_repeats_each_number() {
+    let numbers = make_stream(async move |tx| {
+        for n in [1, 0, 3, 2] {
+            tx.send(n).await;
+        }
+    });
+
+    let items: Vec<usize> = expand(numbers, (), |_, n| vec![n; n]).collect().await;
+    assert_eq!(items, vec![1, 3, 3, 3, 2, 2]);
+}
+
+#[tokio::test]
+async fn expand_threads_state_across_items() {
+    let words = make_stream(async move |tx| {
+        for word in ["ab", "", "c"] {
+            tx.send(word).await;
+        }
+    });
+
+    let items: Vec<(usize, char)> = expand(words, 0, |position, word: &str| {
+        word.chars()
+            .map(|c| {
+                *position += 1;
+                (*position, c)
+            })
+            .collect()
+    })
+    .collect()
+    .await;
+    assert_eq!(items, vec![(1, 'a'), (2, 'b'), (3, 'c')]);
+}
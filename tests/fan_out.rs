@@ -0,0 +1,41 @@
+use futures::sink;
+use futures::Sink;
+use make_async_stream::fan_out;
+use make_async_stream::make_stream;
+
+#[tokio::test]
+async fn fan_out_sends_every_item_to_every_sink() {
+    let numbers = make_stream(async move |tx| {
+        for i in 0..4 {
+            tx.send(i).await;
+        }
+    });
+
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+    fan_out(numbers, vec![&mut left, &mut right]).await.unwrap();
+    assert_eq!(left, vec![0, 1, 2, 3]);
+    assert_eq!(right, vec![0, 1, 2, 3]);
+}
+
+fn failing_at(limit: i32) -> impl Sink<i32, Error = String> {
+    sink::unfold((), move |(), item| async move {
+        if item < limit {
+            Ok(())
+        } else {
+            Err(format!("rejected {item}"))
+        }
+    })
+}
+
+#[tokio::test]
+async fn fan_out_propagates_first_sink_error() {
+    let numbers = make_stream(async move |tx| {
+        for i in 0..4 {
+            tx.send(i).await;
+        }
+    });
+
+    let res = fan_out(numbers, vec![failing_at(3), failing_at(1)]).await;
+    assert_eq!(res, Err("rejected 1".to_string()));
+}
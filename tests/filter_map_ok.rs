@@ -0,0 +1,18 @@
+use futures::StreamExt;
+use make_async_stream::filter_map_ok;
+use make_async_stream::make_try_stream;
+
+#[tokio::test]
+async fn filter_map_ok_filters_oks_and_keeps_error() {
+    let stream = make_try_stream(async move |tx| {
+        for i in 1..=6 {
+            tx.send(i).await;
+        }
+        Err("done".to_string())
+    });
+
+    let items: Vec<Result<i32, String>> = filter_map_ok(stream, |i| (i % 2 == 0).then_some(i * 10))
+        .collect()
+        .await;
+    assert_eq!(items, vec![Ok(20), Ok(40), Ok(60), Err("done".to_string())]);
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "tokio")]
+
+use std::time::Duration;
+
+use futures::StreamExt;
+use make_async_stream::first_item_timeout;
+use make_async_stream::make_stream;
+
+#[tokio::test(start_paused = true)]
+async fn first_item_timeout_fails_a_slow_start() {
+    let slow = make_stream(async move |tx| {
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        tx.send(1).await;
+    });
+
+    let items: Vec<_> = first_item_timeout(slow, Duration::from_secs(1))
+        .collect()
+        .await;
+    assert_eq!(items.len(), 1);
+    assert!(items[0].is_err());
+}
+
+#[tokio::test(start_paused = true)]
+async fn first_item_timeout_disarms_after_first_item() {
+    let long = make_stream(async move |tx| {
+        tx.send(1).await;
+        for i in 2..5 {
+            tokio::time::sleep(Duration::from_secs(10)).await;
+            tx.send(i).await;
+        }
+    });
+
+    let items: Vec<i32> = first_item_timeout(long, Duration::from_secs(1))
+        .map(Result::unwrap)
+        .collect()
+        .await;
+    assert_eq!(items, vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn first_item_timeout_can_be_built_outside_a_runtime() {
+    let stream = make_stream(async move |tx| tx.send(1).await);
+    let stream = first_item_timeout(stream, Duration::from_secs(1));
+
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .start_paused(true)
+        .build()
+        .unwrap();
+    let items: Vec<_> = rt.block_on(async {
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        stream.collect().await
+    });
+    assert_eq!(items.len(), 1);
+    assert!(items[0].is_ok());
+}
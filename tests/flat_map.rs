@@ -0,0 +1,23 @@
+use futures::StreamExt;
+use make_async_stream::flat_map;
+use make_async_stream::make_stream;
+
+#[tokio::test]
+async fn flat_map_drains_each_sub_stream() {
+    let numbers = make_stream(async move |tx| {
+        for n in 1..=4 {
+            tx.send(n).await;
+        }
+    });
+
+    let flattened = flat_map(numbers, |n| {
+        make_stream(async move |tx| {
+            for i in 0..n {
+                tx.send(i).await;
+            }
+        })
+    });
+
+    let items: Vec<i32> = flattened.collect().await;
+    assert_eq!(items, vec![0, 0, 1, 0, 1, 2, 0, 1, 2, 3]);
+}
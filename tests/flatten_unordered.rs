@@ -0,0 +1,42 @@
+use std::time::Duration;
+
+use futures::Stream;
+use futures::StreamExt;
+use make_async_stream::flatten_unordered;
+use make_async_stream::make_stream;
+
+fn ticker(tag: char, count: Option<u32>, period_ms: u64) -> impl Stream<Item = (char, u32)> {
+    make_stream(async move |tx| {
+        let mut i = 0;
+        while count.is_none_or(|count| i < count) {
+            tokio::time::sleep(Duration::from_millis(period_ms)).await;
+            tx.send((tag, i)).await;
+            i += 1;
+        }
+    })
+}
+
+#[tokio::test(start_paused = true)]
+async fn flatten_unordered_interleaves_sub_streams() {
+    let subs = make_stream(async move |tx| {
+        tx.send(ticker('i', None, 10)).await;
+        tx.send(ticker('a', Some(3), 15)).await;
+        tx.send(ticker('b', Some(2), 25)).await;
+        tx.send(ticker('c', Some(2), 5)).await;
+    });
+
+    let items: Vec<(char, u32)> = flatten_unordered(subs, 3).take(20).collect().await;
+
+    for (tag, count) in [('a', 3), ('b', 2), ('c', 2)] {
+        let seen: Vec<u32> = items
+            .iter()
+            .filter(|(t, _)| *t == tag)
+            .map(|(_, i)| *i)
+            .collect();
+        assert_eq!(seen, (0..count).collect::<Vec<_>>(), "{tag}: {items:?}");
+    }
+
+    let a_end = items.iter().position(|item| *item == ('a', 2)).unwrap();
+    let first_infinite = items.iter().position(|item| *item == ('i', 0)).unwrap();
+    assert!(first_infinite < a_end, "{items:?}");
+}
@@ -0,0 +1,50 @@
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use futures::Stream;
+use make_async_stream::make_stream;
+use make_async_stream::AsyncStreamExt;
+use tokio::time::Instant;
+
+fn numbers() -> impl Stream<Item = u64> {
+    make_stream(async move |tx| {
+        for i in 0..8 {
+            tx.send(i).await;
+        }
+    })
+}
+
+async fn run(limit: Option<usize>) -> (Duration, usize) {
+    let seen = AtomicUsize::new(0);
+    let start = Instant::now();
+    numbers()
+        .for_each_concurrent(limit, async |_| {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            seen.fetch_add(1, Ordering::SeqCst);
+        })
+        .await;
+    (start.elapsed(), seen.load(Ordering::SeqCst))
+}
+
+#[tokio::test(start_paused = true)]
+async fn for_each_concurrent_with_limit_one_is_sequential() {
+    let (elapsed, seen) = run(Some(1)).await;
+    assert_eq!(seen, 8);
+    assert!(elapsed >= Duration::from_millis(400), "{elapsed:?}");
+}
+
+#[tokio::test(start_paused = true)]
+async fn for_each_concurrent_bounds_concurrency() {
+    let (elapsed, seen) = run(Some(4)).await;
+    assert_eq!(seen, 8);
+    assert!(elapsed >= Duration::from_millis(100), "{elapsed:?}");
+    assert!(elapsed < Duration::from_millis(150), "{elapsed:?}");
+}
+
+#[tokio::test(start_paused = true)]
+async fn for_each_concurrent_unbounded_runs_all_at_once() {
+    let (elapsed, seen) = run(None).await;
+    assert_eq!(seen, 8);
+    assert!(elapsed < Duration::from_millis(100), "{elapsed:?}");
+}
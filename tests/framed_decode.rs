@@ -0,0 +1,50 @@
+#![cfg(feature = "codec")]
+
+use bytes::Buf;
+use bytes::Bytes;
+use bytes::BytesMut;
+use futures::stream;
+use futures::StreamExt;
+use make_async_stream::framed_decode;
+
+fn length_prefixed(buf: &mut BytesMut) -> Result<Option<Bytes>, String> {
+    let Some(&len) = buf.first() else {
+        return Ok(None);
+    };
+    if len == 0 {
+        return Err("empty frame".to_string());
+    }
+    if buf.len() <= usize::from(len) {
+        return Ok(None);
+    }
+    buf.advance(1);
+    Ok(Some(buf.split_to(usize::from(len)).freeze()))
+}
+
+#[tokio::test]
+async fn framed_decode_joins_frames_split_across_chunks() {
+    let chunks = stream::iter([
+        Bytes::from_static(b"\x03ab"),
+        Bytes::from_static(b"c\x02de"),
+    ]);
+
+    let frames: Vec<_> = framed_decode(chunks, length_prefixed).collect().await;
+    assert_eq!(
+        frames,
+        vec![
+            Ok(Bytes::from_static(b"abc")),
+            Ok(Bytes::from_static(b"de"))
+        ]
+    );
+}
+
+#[tokio::test]
+async fn framed_decode_stops_at_decoder_error() {
+    let chunks = stream::iter([Bytes::from_static(b"\x01a\x00\x01b")]);
+
+    let frames: Vec<_> = framed_decode(chunks, length_prefixed).collect().await;
+    assert_eq!(
+        frames,
+        vec![Ok(Bytes::from_static(b"a")), Err("empty frame".to_string())]
+    );
+}
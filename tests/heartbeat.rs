@@ -0,0 +1,37 @@
+#![cfg(feature = "tokio")]
+
+use std::time::Duration;
+
+use futures::StreamExt;
+use make_async_stream::heartbeat;
+use make_async_stream::make_stream;
+
+#[tokio::test(start_paused = true)]
+async fn heartbeat_interleaves_ticks_with_slow_items() {
+    let slow = make_stream(async move |tx| {
+        tx.send(Some(1)).await;
+        tokio::time::sleep(Duration::from_millis(120)).await;
+        tx.send(Some(2)).await;
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        tx.send(Some(3)).await;
+    });
+
+    let items: Vec<Option<i32>> = heartbeat(slow, Duration::from_millis(50), || None)
+        .collect()
+        .await;
+    assert_eq!(items, vec![Some(1), None, None, Some(2), Some(3)]);
+}
+
+#[test]
+fn heartbeat_can_be_built_outside_a_runtime() {
+    let stream = make_stream(async move |tx| tx.send(Some(1)).await);
+    let stream = heartbeat(stream, Duration::from_millis(50), || None);
+
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .start_paused(true)
+        .build()
+        .unwrap();
+    let items: Vec<Option<i32>> = rt.block_on(stream.collect());
+    assert_eq!(items, vec![Some(1)]);
+}
@@ -0,0 +1,33 @@
+use std::pin::pin;
+
+use futures::stream::FusedStream;
+use futures::StreamExt;
+use make_async_stream::make_stream_limited;
+
+#[tokio::test]
+#[should_panic(expected = "stream exhausted its budget of 5 polls without terminating")]
+async fn limited_stream_fails_on_infinite_generator() {
+    let stream = make_stream_limited(5, async move |tx| {
+        let mut i = 0;
+        loop {
+            tx.send(i).await;
+            i += 1;
+        }
+    });
+
+    stream.collect::<Vec<i32>>().await;
+}
+
+#[tokio::test]
+async fn limited_stream_passes_finite_generator_through() {
+    let stream = make_stream_limited(100, async move |tx| {
+        for i in 0..3 {
+            tx.send(i).await;
+        }
+    });
+    let mut stream = pin!(stream);
+
+    let items: Vec<i32> = stream.as_mut().collect().await;
+    assert_eq!(items, vec![0, 1, 2]);
+    assert!(stream.is_terminated());
+}
@@ -0,0 +1,23 @@
+use std::pin::pin;
+
+use futures::stream::FusedStream;
+use futures::StreamExt;
+use make_async_stream::make_stream;
+use make_async_stream::map_while;
+
+#[tokio::test]
+async fn map_while_stops_at_first_none() {
+    let numbers = make_stream(async move |tx| {
+        for i in [2, 4, 5, 6] {
+            tx.send(i).await;
+        }
+    });
+    let mut stream = pin!(map_while(numbers, |i| (i % 2 == 0).then_some(i)));
+
+    assert!(!stream.is_terminated());
+    assert_eq!(stream.next().await, Some(2));
+    assert_eq!(stream.next().await, Some(4));
+    assert_eq!(stream.next().await, None);
+    assert!(stream.is_terminated());
+    assert_eq!(stream.next().await, None);
+}
@@ -0,0 +1,34 @@
+use std::time::Duration;
+use std::time::Instant;
+
+use futures::StreamExt;
+use make_async_stream::make_stream;
+use make_async_stream::measure_rate;
+
+#[tokio::test]
+async fn measure_rate_matches_elapsed_time() {
+    let ticks = make_stream(async move |tx| {
+        for i in 0..20 {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            tx.send(i).await;
+        }
+    });
+    let (stream, handle) = measure_rate(ticks);
+    assert_eq!(handle.items_per_sec(), 0.0);
+
+    let start = Instant::now();
+    let items: Vec<i32> = stream.collect().await;
+    let expected = items.len() as f64 / start.elapsed().as_secs_f64();
+
+    assert_eq!(handle.items(), 20);
+    let rate = handle.items_per_sec();
+    assert!(rate > 0.0);
+    assert!(
+        rate > expected * 0.5 && rate < expected * 2.0,
+        "{rate} vs {expected}"
+    );
+
+    // The clock stops once the stream ends.
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(handle.items_per_sec(), rate);
+}
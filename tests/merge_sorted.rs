@@ -0,0 +1,29 @@
+use futures::Stream;
+use futures::StreamExt;
+use make_async_stream::make_stream;
+use make_async_stream::merge_sorted;
+
+fn sorted(items: Vec<u32>) -> impl Stream<Item = u32> {
+    make_stream(async move |tx| {
+        for item in items {
+            tokio::task::yield_now().await;
+            tx.send(item).await;
+        }
+    })
+}
+
+#[tokio::test]
+async fn merge_sorted_interleaves_sorted_generators() {
+    let items: Vec<u32> = merge_sorted(sorted(vec![1, 3, 5]), sorted(vec![2, 4, 6]))
+        .collect()
+        .await;
+    assert_eq!(items, vec![1, 2, 3, 4, 5, 6]);
+}
+
+#[tokio::test]
+async fn merge_sorted_keeps_duplicates_and_drains_longer_side() {
+    let items: Vec<u32> = merge_sorted(sorted(vec![1, 2, 2]), sorted(vec![2, 3, 7, 9]))
+        .collect()
+        .await;
+    assert_eq!(items, vec![1, 2, 2, 2, 3, 7, 9]);
+}
@@ -0,0 +1,72 @@
+use std::cell::RefCell;
+use std::future;
+use std::future::Future;
+use std::mem;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::thread;
+
+use futures::StreamExt;
+use make_async_stream::make_stream;
+use make_async_stream::Sender;
+
+#[test]
+fn send_from_scoped_thread_is_detected() {
+    let messages: Vec<String> = futures::executor::block_on(
+        make_stream(async move |tx| {
+            let err = thread::scope(|s| {
+                s.spawn(|| futures::executor::block_on(tx.send("lost".to_string())))
+                    .join()
+                    .unwrap_err()
+            });
+            let msg = match err.downcast_ref::<&str>() {
+                Some(msg) => msg.to_string(),
+                None => err.downcast_ref::<String>().cloned().unwrap_or_default(),
+            };
+            tx.send(msg).await;
+        })
+        .collect(),
+    );
+
+    assert_eq!(messages.len(), 1);
+    assert!(
+        messages[0].contains("`Sender` used outside of its generator"),
+        "{}",
+        messages[0]
+    );
+}
+
+#[test]
+#[should_panic(expected = "`Sender` used inside the generator of another stream")]
+fn send_into_foreign_stream_is_detected() {
+    type Stashed = Rc<RefCell<Option<Pin<Box<dyn Future<Output = ()>>>>>>;
+    let stashed: Stashed = Rc::default();
+
+    let slot = stashed.clone();
+    let mut bytes = Box::pin(make_stream(async move |tx: &mut Sender<u8>| {
+        let send: Pin<Box<dyn Future<Output = ()> + '_>> = Box::pin(tx.send(1));
+        // Safe code can't get a send out of its generator. This one stays valid since its
+        // generator never resumes and `bytes` outlives the foreign stream.
+        #[allow(unsafe_code)]
+        let send = unsafe {
+            mem::transmute::<
+                Pin<Box<dyn Future<Output = ()> + '_>>,
+                Pin<Box<dyn Future<Output = ()>>>,
+            >(send)
+        };
+        *slot.borrow_mut() = Some(send);
+        future::pending::<()>().await;
+    }));
+
+    futures::executor::block_on(async {
+        assert!(futures::poll!(bytes.next()).is_pending());
+
+        let slot = stashed.clone();
+        let strings = make_stream(async move |tx: &mut Sender<String>| {
+            let send = slot.borrow_mut().take().unwrap();
+            send.await;
+            tx.send("unreachable".to_string()).await;
+        });
+        strings.collect::<Vec<String>>().await;
+    });
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "json")]
+
+use std::collections::BTreeMap;
+
+use futures::stream;
+use futures::StreamExt;
+use make_async_stream::make_stream;
+use make_async_stream::ndjson_encode;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[tokio::test]
+async fn ndjson_encode_round_trips_each_line() {
+    let points = make_stream(async move |tx| {
+        for i in 0..3 {
+            tx.send(Point { x: i, y: -i }).await;
+        }
+    });
+
+    let lines: Vec<Vec<u8>> = ndjson_encode(points).map(Result::unwrap).collect().await;
+    assert_eq!(lines.len(), 3);
+    for (i, line) in (0..).zip(&lines) {
+        assert_eq!(line.last(), Some(&b'\n'));
+        let point: Point = serde_json::from_slice(line).unwrap();
+        assert_eq!(point, Point { x: i, y: -i });
+    }
+}
+
+#[tokio::test]
+async fn ndjson_encode_yields_serialization_errors() {
+    let maps = stream::iter([BTreeMap::from([(vec![1], 1)]), BTreeMap::new()]);
+
+    let lines: Vec<_> = ndjson_encode(maps).collect().await;
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].is_err());
+    assert_eq!(lines[1].as_ref().unwrap(), b"{}\n");
+}
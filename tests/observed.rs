@@ -0,0 +1,44 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use futures::StreamExt;
+use make_async_stream::make_stream_observed;
+use make_async_stream::ObserveEvent;
+
+#[tokio::test]
+async fn observer_sees_poll_lifecycle() {
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let recorded = events.clone();
+    let observer = move |event: ObserveEvent<&i32>| {
+        let event = match event {
+            ObserveEvent::Polled => ObserveEvent::Polled,
+            ObserveEvent::Yielded(item) => ObserveEvent::Yielded(*item),
+            ObserveEvent::Pending => ObserveEvent::Pending,
+            ObserveEvent::Terminated => ObserveEvent::Terminated,
+        };
+        recorded.borrow_mut().push(event);
+    };
+
+    let stream = make_stream_observed(observer, async move |tx| {
+        tx.send(1).await;
+        tokio::task::yield_now().await;
+        tx.send(2).await;
+    });
+    let items: Vec<i32> = stream.collect().await;
+    assert_eq!(items, vec![1, 2]);
+
+    use ObserveEvent::*;
+    assert_eq!(
+        *events.borrow(),
+        vec![
+            Polled,
+            Yielded(1),
+            Polled,
+            Pending,
+            Polled,
+            Yielded(2),
+            Polled,
+            Terminated,
+        ]
+    );
+}
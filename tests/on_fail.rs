@@ -0,0 +1,43 @@
+use std::pin::pin;
+
+use futures::StreamExt;
+use make_async_stream::make_try_stream;
+use make_async_stream::AsyncStreamExt;
+use make_async_stream::OnFail;
+
+#[tokio::test]
+async fn fail_terminates_by_default() {
+    let mut items = pin!(make_try_stream(async move |tx| {
+        tx.send(1).await;
+        tx.fail("bad item".to_string()).await;
+        tx.send(2).await;
+        Ok(())
+    }));
+
+    assert_eq!(items.try_next().await, Ok(Some(1)));
+    assert_eq!(items.try_next().await, Err("bad item".to_string()));
+    assert_eq!(items.try_next().await, Ok(None));
+}
+
+#[tokio::test]
+async fn fail_continues_when_configured() {
+    let items: Vec<Result<u32, String>> = make_try_stream(async move |tx| {
+        tx.on_fail(OnFail::Continue);
+        tx.send(1).await;
+        tx.fail("bad item".to_string()).await;
+        tx.send(2).await;
+        Err("fatal".to_string())
+    })
+    .collect()
+    .await;
+
+    assert_eq!(
+        items,
+        vec![
+            Ok(1),
+            Err("bad item".to_string()),
+            Ok(2),
+            Err("fatal".to_string()),
+        ]
+    );
+}
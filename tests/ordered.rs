@@ -0,0 +1,40 @@
+use futures::StreamExt;
+use make_async_stream::make_ordered_stream;
+use make_async_stream::make_stream;
+
+#[tokio::test]
+async fn racing_sends_follow_poll_order() {
+    for _ in 0..100 {
+        let stream = make_stream(async move |tx| {
+            let first = tx.send(1);
+            let second = tx.send(2);
+            futures::join!(second, first);
+        });
+        let items: Vec<i32> = stream.collect().await;
+        assert_eq!(items, vec![2, 1]);
+    }
+}
+
+#[tokio::test]
+async fn ordered_racing_sends_follow_creation_order() {
+    for _ in 0..100 {
+        let stream = make_ordered_stream(async move |tx| {
+            let first = tx.send(1);
+            let second = tx.send(2);
+            let third = tx.send(3);
+            futures::join!(third, second, first);
+        });
+        let items: Vec<i32> = stream.collect().await;
+        assert_eq!(items, vec![1, 2, 3]);
+    }
+}
+
+#[tokio::test]
+async fn ordered_dropped_send_does_not_block_later_sends() {
+    let stream = make_ordered_stream(async move |tx| {
+        drop(tx.send(1));
+        tx.send(2).await;
+    });
+    let items: Vec<i32> = stream.collect().await;
+    assert_eq!(items, vec![2]);
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "tokio")]
+
+use std::time::Duration;
+use std::time::Instant;
+
+use futures::StreamExt;
+use make_async_stream::make_stream;
+use make_async_stream::par_map;
+
+fn numbers(n: u64) -> impl futures::Stream<Item = u64> {
+    make_stream(async move |tx| {
+        for i in 0..n {
+            tx.send(i).await;
+        }
+    })
+}
+
+fn slow_square(n: u64) -> u64 {
+    std::thread::sleep(Duration::from_millis(50));
+    n * n
+}
+
+#[tokio::test]
+async fn par_map_ordered_beats_serial() {
+    let start = Instant::now();
+    let items: Vec<u64> = par_map(numbers(8), slow_square, 4, true).collect().await;
+    let elapsed = start.elapsed();
+
+    assert_eq!(items, vec![0, 1, 4, 9, 16, 25, 36, 49]);
+    assert!(elapsed < Duration::from_millis(8 * 50), "{elapsed:?}");
+}
+
+#[tokio::test]
+async fn par_map_unordered_yields_every_result() {
+    let start = Instant::now();
+    let mut items: Vec<u64> = par_map(numbers(8), slow_square, 4, false).collect().await;
+    let elapsed = start.elapsed();
+
+    items.sort();
+    assert_eq!(items, vec![0, 1, 4, 9, 16, 25, 36, 49]);
+    assert!(elapsed < Duration::from_millis(8 * 50), "{elapsed:?}");
+}
@@ -0,0 +1,15 @@
+use make_async_stream::make_stream;
+use make_async_stream::AsyncStreamExt;
+
+#[tokio::test]
+async fn partition_splits_evens_and_odds() {
+    let numbers = make_stream(async move |tx| {
+        for i in 0..10 {
+            tx.send(i).await;
+        }
+    });
+
+    let (evens, odds): (Vec<i32>, Vec<i32>) = numbers.partition(|i| i % 2 == 0).await;
+    assert_eq!(evens, vec![0, 2, 4, 6, 8]);
+    assert_eq!(odds, vec![1, 3, 5, 7, 9]);
+}
@@ -0,0 +1,34 @@
+use std::pin::pin;
+use std::time::Duration;
+
+use futures::StreamExt;
+use make_async_stream::make_stream;
+use make_async_stream::pausable;
+
+#[tokio::test]
+async fn pausable_holds_items_until_resumed() {
+    let numbers = make_stream(async move |tx| {
+        for i in 0..4 {
+            tx.send(i).await;
+        }
+    });
+    let (stream, handle) = pausable(numbers);
+    let mut stream = pin!(stream);
+
+    assert_eq!(stream.next().await, Some(0));
+
+    handle.pause();
+    assert!(handle.is_paused());
+    let res = tokio::time::timeout(Duration::from_millis(50), stream.next()).await;
+    assert!(res.is_err());
+
+    let resumer = handle.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        resumer.resume();
+    });
+
+    let rest: Vec<i32> = stream.collect().await;
+    assert_eq!(rest, vec![1, 2, 3]);
+    assert!(!handle.is_paused());
+}
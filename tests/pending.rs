@@ -0,0 +1,19 @@
+use std::task::Context;
+use std::task::Poll;
+
+use futures::stream::FusedStream;
+use futures::task::noop_waker;
+use futures::Stream;
+use futures::StreamExt;
+use make_async_stream::pending;
+
+#[test]
+fn pending_never_yields() {
+    let mut stream = pending::<i32>();
+    let waker = noop_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+    assert!(!stream.is_terminated());
+    assert_eq!(stream.size_hint(), (0, None));
+}
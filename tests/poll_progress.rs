@@ -0,0 +1,40 @@
+use std::pin::pin;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use futures::task::noop_waker;
+use futures::Stream;
+use futures::StreamExt;
+use make_async_stream::make_stream;
+
+#[tokio::test]
+async fn poll_progress_buffers_items_for_poll_next() {
+    let produced = Arc::new(AtomicUsize::new(0));
+    let counter = produced.clone();
+    let stream = make_stream(async move |tx| {
+        for i in 0..3 {
+            counter.fetch_add(1, Ordering::SeqCst);
+            tx.send(i).await;
+        }
+    });
+    let mut stream = pin!(stream);
+
+    let waker = noop_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    for _ in 0..3 {
+        assert_eq!(stream.as_mut().poll_progress(&mut cx), Poll::Ready(()));
+    }
+    assert_eq!(produced.load(Ordering::SeqCst), 1);
+    assert_eq!(stream.size_hint(), (1, None));
+
+    assert_eq!(stream.as_mut().poll_next(&mut cx), Poll::Ready(Some(0)));
+    assert_eq!(stream.as_mut().poll_progress(&mut cx), Poll::Ready(()));
+    assert_eq!(produced.load(Ordering::SeqCst), 2);
+
+    let rest: Vec<i32> = stream.collect().await;
+    assert_eq!(rest, vec![1, 2]);
+}
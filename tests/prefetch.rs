@@ -0,0 +1,51 @@
+#![cfg(feature = "tokio")]
+
+use std::time::Duration;
+
+use futures::Stream;
+use futures::StreamExt;
+use make_async_stream::make_stream;
+use make_async_stream::prefetch;
+use tokio::time::Instant;
+
+fn slow_numbers() -> impl Stream<Item = u32> + Send + 'static {
+    make_stream(async move |tx| {
+        for i in 0..4 {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            tx.send(i).await;
+        }
+    })
+}
+
+async fn consume_slowly(stream: impl Stream<Item = u32>) -> (Vec<u32>, Duration) {
+    let start = Instant::now();
+    let mut stream = std::pin::pin!(stream);
+    let mut items = Vec::new();
+    while let Some(item) = stream.next().await {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        items.push(item);
+    }
+    (items, start.elapsed())
+}
+
+#[tokio::test(start_paused = true)]
+async fn prefetch_overlaps_producer_and_consumer() {
+    let (items, serial) = consume_slowly(slow_numbers()).await;
+    assert_eq!(items, vec![0, 1, 2, 3]);
+    assert!(serial >= Duration::from_millis(400), "{serial:?}");
+
+    let (items, prefetched) = consume_slowly(prefetch(slow_numbers(), 2)).await;
+    assert_eq!(items, vec![0, 1, 2, 3]);
+    assert!(prefetched < Duration::from_millis(300), "{prefetched:?}");
+}
+
+#[tokio::test]
+#[should_panic(expected = "producer failed")]
+async fn prefetch_resumes_producer_panic() {
+    let failing = make_stream(async move |tx| {
+        tx.send(1).await;
+        panic!("producer failed");
+    });
+
+    prefetch(failing, 1).collect::<Vec<u32>>().await;
+}
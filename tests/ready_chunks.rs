@@ -0,0 +1,21 @@
+use futures::StreamExt;
+use make_async_stream::make_stream;
+use make_async_stream::ready_chunks;
+
+#[tokio::test]
+async fn ready_chunks_batches_bursts() {
+    let bursts = make_stream(async move |tx| {
+        for burst in [0..3, 3..5, 5..10] {
+            for i in burst {
+                tx.send(i).await;
+            }
+            tokio::task::yield_now().await;
+        }
+    });
+
+    let batches: Vec<Vec<i32>> = ready_chunks(bursts, 4).collect().await;
+    assert_eq!(
+        batches,
+        vec![vec![0, 1, 2], vec![3, 4], vec![5, 6, 7, 8], vec![9]]
+    );
+}
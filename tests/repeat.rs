@@ -0,0 +1,12 @@
+use futures::Stream;
+use futures::StreamExt;
+use make_async_stream::repeat;
+
+#[tokio::test]
+async fn repeat_yields_value_forever() {
+    let stream = repeat(7);
+    assert_eq!(stream.size_hint(), (usize::MAX, None));
+
+    let items: Vec<i32> = stream.take(3).collect().await;
+    assert_eq!(items, vec![7, 7, 7]);
+}
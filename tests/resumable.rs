@@ -0,0 +1,27 @@
+use futures::StreamExt;
+use make_async_stream::make_resumable_stream;
+use make_async_stream::ResumableSender;
+
+async fn count_to_ten(tx: &mut ResumableSender<u32, u32>, start: u32) -> u32 {
+    for i in start..10 {
+        tx.checkpoint(i);
+        tx.send(i).await;
+    }
+    10
+}
+
+#[tokio::test]
+async fn resumable_stream_resumes_from_persisted_checkpoint() {
+    let (stream, handle) = make_resumable_stream(0, count_to_ten);
+    assert_eq!(handle.latest(), 0);
+
+    let items: Vec<u32> = stream.take(4).collect().await;
+    assert_eq!(items, vec![0, 1, 2, 3]);
+    let persisted = handle.latest();
+    assert_eq!(persisted, 3);
+
+    let (stream, handle) = make_resumable_stream(persisted, count_to_ten);
+    let items: Vec<u32> = stream.collect().await;
+    assert_eq!(items, (3..10).collect::<Vec<_>>());
+    assert_eq!(handle.latest(), 10);
+}
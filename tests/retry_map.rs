@@ -0,0 +1,40 @@
+use std::collections::HashMap;
+
+use futures::stream;
+use futures::StreamExt;
+use make_async_stream::retry_map;
+
+#[tokio::test]
+async fn retry_map_retries_flaky_items() {
+    let mut attempts = HashMap::new();
+    let results: Vec<Result<i32, String>> = retry_map(stream::iter([1, 2, 3]), 2, async move |i| {
+        let attempt = attempts.entry(*i).or_insert(0);
+        *attempt += 1;
+        if *i == 2 && *attempt <= 2 {
+            Err(format!("attempt {attempt} failed"))
+        } else {
+            Ok(i * 10)
+        }
+    })
+    .collect()
+    .await;
+    assert_eq!(results, vec![Ok(10), Ok(20), Ok(30)]);
+}
+
+#[tokio::test]
+async fn retry_map_continues_after_exhausted_item() {
+    let mut attempts = 0;
+    let results: Vec<Result<i32, String>> = retry_map(stream::iter([1, 2, 3]), 1, async move |i| {
+        if *i == 2 {
+            attempts += 1;
+            return Err(format!("attempt {attempts} failed"));
+        }
+        Ok(i * 10)
+    })
+    .collect()
+    .await;
+    assert_eq!(
+        results,
+        vec![Ok(10), Err("attempt 2 failed".to_string()), Ok(30)]
+    );
+}
@@ -0,0 +1,24 @@
+use futures::stream;
+use futures::StreamExt;
+use make_async_stream::rle_decode;
+use make_async_stream::rle_encode;
+
+#[tokio::test]
+async fn rle_encode_collapses_runs() {
+    let runs: Vec<(char, usize)> = rle_encode(stream::iter("aaabccdddd".chars()))
+        .collect()
+        .await;
+    assert_eq!(runs, vec![('a', 3), ('b', 1), ('c', 2), ('d', 4)]);
+}
+
+#[tokio::test]
+async fn rle_decode_reverses_rle_encode() {
+    let original = vec![1, 1, 2, 3, 3, 3, 1];
+    let decoded: Vec<i32> = rle_decode(rle_encode(stream::iter(original.clone())))
+        .collect()
+        .await;
+    assert_eq!(decoded, original);
+
+    let decoded: Vec<i32> = rle_decode(stream::iter([(5, 0), (6, 2)])).collect().await;
+    assert_eq!(decoded, vec![6, 6]);
+}
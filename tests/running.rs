@@ -0,0 +1,32 @@
+use futures::Stream;
+use futures::StreamExt;
+use make_async_stream::make_stream;
+use make_async_stream::running_max;
+use make_async_stream::running_min;
+use make_async_stream::running_sum;
+
+fn input() -> impl Stream<Item = i32> {
+    make_stream(async move |tx| {
+        for i in [3, 1, 4, 1, 5] {
+            tx.send(i).await;
+        }
+    })
+}
+
+#[tokio::test]
+async fn running_sum_accumulates() {
+    let items: Vec<i32> = running_sum(input()).collect().await;
+    assert_eq!(items, vec![3, 4, 8, 9, 14]);
+}
+
+#[tokio::test]
+async fn running_max_tracks_maximum() {
+    let items: Vec<i32> = running_max(input()).collect().await;
+    assert_eq!(items, vec![3, 3, 4, 4, 5]);
+}
+
+#[tokio::test]
+async fn running_min_tracks_minimum() {
+    let items: Vec<i32> = running_min(input()).collect().await;
+    assert_eq!(items, vec![3, 1, 1, 1, 1]);
+}
@@ -0,0 +1,34 @@
+#![cfg(feature = "tokio")]
+
+use std::time::Duration;
+
+use futures::StreamExt;
+use make_async_stream::make_stream;
+use make_async_stream::sample;
+
+#[tokio::test(start_paused = true)]
+async fn sample_yields_latest_item_per_period() {
+    let fast = make_stream(async move |tx| {
+        for i in 0..10 {
+            if i > 0 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+            tx.send(i).await;
+        }
+    });
+
+    let items: Vec<i32> = sample(fast, Duration::from_millis(33)).collect().await;
+    assert_eq!(items, vec![3, 6, 9]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn sample_skips_ticks_without_new_items() {
+    let slow = make_stream(async move |tx| {
+        tx.send(1).await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        tx.send(2).await;
+    });
+
+    let items: Vec<i32> = sample(slow, Duration::from_millis(30)).collect().await;
+    assert_eq!(items, vec![1, 2]);
+}
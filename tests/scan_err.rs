@@ -0,0 +1,31 @@
+use futures::StreamExt;
+use make_async_stream::make_try_stream;
+use make_async_stream::scan_err;
+
+#[tokio::test]
+async fn scan_err_reports_successes_before_error() {
+    let stream = make_try_stream(async move |tx| {
+        for i in 0..3 {
+            tx.send(i).await;
+        }
+        Err("connection reset")
+    });
+
+    let items: Vec<Result<i32, String>> = scan_err(
+        stream,
+        0,
+        |count, _| *count += 1,
+        |count, err| format!("{err} after {count} items"),
+    )
+    .collect()
+    .await;
+    assert_eq!(
+        items,
+        vec![
+            Ok(0),
+            Ok(1),
+            Ok(2),
+            Err("connection reset after 3 items".to_string())
+        ]
+    );
+}
@@ -0,0 +1,55 @@
+use futures::StreamExt;
+use make_async_stream::select_all;
+use make_async_stream::Sender;
+
+#[tokio::test]
+async fn select_all_tags_items_with_source_index() {
+    let generator = |len: u32| {
+        async move |tx: &mut Sender<u32>| {
+            for i in 0..len {
+                tx.send(i).await;
+                tokio::task::yield_now().await;
+            }
+        }
+    };
+
+    let items: Vec<(usize, u32)> = select_all(vec![generator(1), generator(3), generator(2)])
+        .collect()
+        .await;
+
+    let mut sorted = items.clone();
+    sorted.sort();
+    assert_eq!(sorted, vec![(0, 0), (1, 0), (1, 1), (1, 2), (2, 0), (2, 1)]);
+
+    for index in 0..3 {
+        let from_source: Vec<u32> = items
+            .iter()
+            .filter(|(i, _)| *i == index)
+            .map(|(_, item)| *item)
+            .collect();
+        assert!(from_source.is_sorted(), "{items:?}");
+    }
+}
+
+#[tokio::test]
+async fn select_all_drains_remaining_generators_as_others_finish() {
+    let generator = |len: u32| {
+        async move |tx: &mut Sender<u32>| {
+            for i in 0..len {
+                tokio::task::yield_now().await;
+                tx.send(i).await;
+            }
+        }
+    };
+
+    let lens = [0, 2, 0, 3, 1, 0, 2];
+    let mut items: Vec<(usize, u32)> = select_all(lens.map(generator)).collect().await;
+    items.sort();
+
+    let expected: Vec<(usize, u32)> = lens
+        .iter()
+        .enumerate()
+        .flat_map(|(index, &len)| (0..len).map(move |i| (index, i)))
+        .collect();
+    assert_eq!(items, expected);
+}
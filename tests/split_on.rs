@@ -0,0 +1,20 @@
+use futures::stream;
+use futures::StreamExt;
+use make_async_stream::split_on;
+
+#[tokio::test]
+async fn split_on_groups_items_between_delimiters() {
+    let tokens = stream::iter(["a", "b", ";", "c", ";", "d"]);
+    let groups: Vec<Vec<&str>> = split_on(tokens, ";").collect().await;
+    assert_eq!(groups, vec![vec!["a", "b"], vec!["c"], vec!["d"]]);
+}
+
+#[tokio::test]
+async fn split_on_emits_empty_groups_around_delimiters() {
+    let tokens = stream::iter([0, 1, 0, 0]);
+    let groups: Vec<Vec<i32>> = split_on(tokens, 0).collect().await;
+    assert_eq!(groups, vec![vec![], vec![1], vec![], vec![]]);
+
+    let groups: Vec<Vec<i32>> = split_on(stream::empty(), 0).collect().await;
+    assert!(groups.is_empty());
+}
@@ -0,0 +1,57 @@
+use futures::StreamExt;
+use make_async_stream::make_state_machine;
+use make_async_stream::Step;
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token {
+    Number(u32),
+    Plus,
+    Minus,
+}
+
+struct Tokenizer {
+    input: Vec<char>,
+    pos: usize,
+}
+
+#[tokio::test]
+async fn state_machine_tokenizes_input() {
+    let tokenizer = Tokenizer {
+        input: "12 + 345 -6".chars().collect(),
+        pos: 0,
+    };
+
+    let tokens = make_state_machine(tokenizer, async move |t: &mut Tokenizer| {
+        let Some(&c) = t.input.get(t.pos) else {
+            return Step::Done;
+        };
+        t.pos += 1;
+
+        match c {
+            ' ' => Step::Skip,
+            '+' => Step::Emit(Token::Plus),
+            '-' => Step::Emit(Token::Minus),
+            '0'..='9' => {
+                let mut n = c.to_digit(10).unwrap();
+                while let Some(d) = t.input.get(t.pos).and_then(|c| c.to_digit(10)) {
+                    n = n * 10 + d;
+                    t.pos += 1;
+                }
+                Step::Emit(Token::Number(n))
+            }
+            _ => Step::Done,
+        }
+    });
+
+    let tokens: Vec<Token> = tokens.collect().await;
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Number(12),
+            Token::Plus,
+            Token::Number(345),
+            Token::Minus,
+            Token::Number(6),
+        ]
+    );
+}
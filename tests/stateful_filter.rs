@@ -0,0 +1,26 @@
+use futures::StreamExt;
+use make_async_stream::make_stream;
+use make_async_stream::stateful_filter;
+
+#[tokio::test]
+async fn stateful_filter_keeps_every_third_item() {
+    let numbers = make_stream(async move |tx| {
+        for i in 0..10 {
+            tx.send(i).await;
+            tokio::task::yield_now().await;
+        }
+    });
+
+    let items: Vec<u32> = stateful_filter(numbers, 0, |skipped: &mut usize, _| {
+        if *skipped == 2 {
+            *skipped = 0;
+            true
+        } else {
+            *skipped += 1;
+            false
+        }
+    })
+    .collect()
+    .await;
+    assert_eq!(items, vec![2, 5, 8]);
+}
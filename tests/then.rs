@@ -0,0 +1,36 @@
+use std::time::Duration;
+
+use futures::StreamExt;
+use make_async_stream::make_stream;
+use make_async_stream::then;
+
+#[tokio::test(start_paused = true)]
+async fn then_preserves_order() {
+    let numbers = make_stream(async move |tx| {
+        for i in 1..=4u64 {
+            tx.send(i).await;
+        }
+    });
+
+    let items: Vec<u64> = then(numbers, async |i| {
+        tokio::time::sleep(Duration::from_millis(50 / i)).await;
+        i * 10
+    })
+    .collect()
+    .await;
+    assert_eq!(items, vec![10, 20, 30, 40]);
+}
+
+#[tokio::test]
+async fn then_borrows_from_the_caller() {
+    let factor = 10;
+    let factor = &factor;
+
+    let items: Vec<i32> = then(
+        futures::stream::iter([1, 2, 3]),
+        |i| async move { i * factor },
+    )
+    .collect()
+    .await;
+    assert_eq!(items, vec![10, 20, 30]);
+}
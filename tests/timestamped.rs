@@ -0,0 +1,26 @@
+use std::time::Duration;
+use std::time::Instant;
+
+use futures::StreamExt;
+use make_async_stream::make_stream;
+use make_async_stream::timestamped;
+
+#[tokio::test]
+async fn timestamps_are_monotonic() {
+    let start = Instant::now();
+    let stream = make_stream(async move |tx| {
+        for i in 0..5 {
+            tx.send(i).await;
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    });
+
+    let items: Vec<(i32, Instant)> = timestamped(stream).collect().await;
+    let values: Vec<i32> = items.iter().map(|(v, _)| *v).collect();
+    assert_eq!(values, vec![0, 1, 2, 3, 4]);
+
+    assert!(items[0].1 >= start);
+    for pair in items.windows(2) {
+        assert!(pair[0].1 <= pair[1].1);
+    }
+}
@@ -0,0 +1,58 @@
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::StreamExt;
+use make_async_stream::make_stream;
+use make_async_stream::try_buffered;
+
+async fn job(
+    id: u32,
+    delay_ms: u64,
+    fail: bool,
+    finished: Arc<AtomicUsize>,
+) -> Result<u32, String> {
+    tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+    finished.fetch_add(1, Ordering::SeqCst);
+    if fail {
+        Err(format!("job {id} failed"))
+    } else {
+        Ok(id)
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn try_buffered_yields_oks_as_they_complete() {
+    let finished = Arc::new(AtomicUsize::new(0));
+    let counter = finished.clone();
+    let jobs = make_stream(async move |tx| {
+        tx.send(job(1, 30, false, counter.clone())).await;
+        tx.send(job(2, 10, false, counter.clone())).await;
+        tx.send(job(3, 20, false, counter.clone())).await;
+    });
+
+    let items: Vec<Result<u32, String>> = try_buffered(jobs, 3).collect().await;
+    assert_eq!(items, vec![Ok(2), Ok(3), Ok(1)]);
+    assert_eq!(finished.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn try_buffered_stops_at_first_error() {
+    let finished = Arc::new(AtomicUsize::new(0));
+    let counter = finished.clone();
+    let jobs = make_stream(async move |tx| {
+        tx.send(job(1, 5, false, counter.clone())).await;
+        tx.send(job(2, 100, false, counter.clone())).await;
+        tx.send(job(3, 10, true, counter.clone())).await;
+        tx.send(job(4, 100, false, counter.clone())).await;
+        tx.send(job(5, 1, false, counter.clone())).await;
+    });
+
+    let items: Vec<Result<u32, String>> = try_buffered(jobs, 3).collect().await;
+    assert_eq!(items, vec![Ok(1), Err("job 3 failed".to_string())]);
+
+    // Jobs 2 and 4 were cancelled in flight, and job 5 was never pulled.
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    assert_eq!(finished.load(Ordering::SeqCst), 2);
+}
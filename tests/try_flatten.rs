@@ -0,0 +1,59 @@
+use futures::StreamExt;
+use make_async_stream::make_try_stream;
+use make_async_stream::try_flatten;
+
+#[tokio::test]
+async fn try_flatten_concatenates_sub_streams() {
+    let outer = make_try_stream(async move |tx| {
+        for n in 1..=3 {
+            tx.send(make_try_stream(async move |tx| {
+                for i in 0..n {
+                    tx.send(i).await;
+                }
+                Ok::<(), String>(())
+            }))
+            .await;
+        }
+        Ok(())
+    });
+
+    let items: Vec<Result<i32, String>> = try_flatten(outer).collect().await;
+    assert_eq!(items, vec![Ok(0), Ok(0), Ok(1), Ok(0), Ok(1), Ok(2)]);
+}
+
+#[tokio::test]
+async fn try_flatten_stops_at_inner_error() {
+    let outer = make_try_stream(async move |tx| {
+        for n in 1..=3 {
+            tx.send(make_try_stream(async move |tx| {
+                for i in 0..n {
+                    if n == 2 && i == 1 {
+                        return Err(format!("failed at {n}/{i}"));
+                    }
+                    tx.send(i).await;
+                }
+                Ok(())
+            }))
+            .await;
+        }
+        Ok(())
+    });
+
+    let items: Vec<Result<i32, String>> = try_flatten(outer).collect().await;
+    assert_eq!(items, vec![Ok(0), Ok(0), Err("failed at 2/1".to_string())]);
+}
+
+#[tokio::test]
+async fn try_flatten_stops_at_outer_error() {
+    let outer = make_try_stream(async move |tx| {
+        tx.send(make_try_stream(async move |tx| {
+            tx.send(1).await;
+            Ok::<(), String>(())
+        }))
+        .await;
+        Err("outer failed".to_string())
+    });
+
+    let items: Vec<Result<i32, String>> = try_flatten(outer).collect().await;
+    assert_eq!(items, vec![Ok(1), Err("outer failed".to_string())]);
+}
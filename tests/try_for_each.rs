@@ -0,0 +1,110 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::time::Duration;
+
+use futures::Stream;
+use make_async_stream::make_try_stream;
+use make_async_stream::AsyncStreamExt;
+
+fn numbers(fail_at: Option<u32>) -> impl Stream<Item = Result<u32, String>> {
+    make_try_stream(async move |tx| {
+        for i in 0..5 {
+            if Some(i) == fail_at {
+                return Err(format!("stream failed at {i}"));
+            }
+            tx.send(i).await;
+        }
+        Ok(())
+    })
+}
+
+#[tokio::test]
+async fn try_for_each_visits_every_item() {
+    let seen = RefCell::new(Vec::new());
+    let res = numbers(None)
+        .try_for_each(async |i| {
+            seen.borrow_mut().push(i);
+            Ok(())
+        })
+        .await;
+    assert_eq!(res, Ok(()));
+    assert_eq!(*seen.borrow(), vec![0, 1, 2, 3, 4]);
+}
+
+#[tokio::test]
+async fn try_for_each_stops_at_error_from_f() {
+    let seen = RefCell::new(Vec::new());
+    let res = numbers(None)
+        .try_for_each(async |i| {
+            if i == 2 {
+                return Err(format!("f failed at {i}"));
+            }
+            seen.borrow_mut().push(i);
+            Ok(())
+        })
+        .await;
+    assert_eq!(res, Err("f failed at 2".to_string()));
+    assert_eq!(*seen.borrow(), vec![0, 1]);
+}
+
+#[tokio::test]
+async fn try_for_each_stops_at_error_item() {
+    let seen = RefCell::new(Vec::new());
+    let res = numbers(Some(3))
+        .try_for_each(async |i| {
+            seen.borrow_mut().push(i);
+            Ok(())
+        })
+        .await;
+    assert_eq!(res, Err("stream failed at 3".to_string()));
+    assert_eq!(*seen.borrow(), vec![0, 1, 2]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn try_for_each_concurrent_visits_every_item() {
+    let seen = Cell::new(0);
+    let res = numbers(None)
+        .try_for_each_concurrent(2, async |_| {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            seen.set(seen.get() + 1);
+            Ok(())
+        })
+        .await;
+    assert_eq!(res, Ok(()));
+    assert_eq!(seen.get(), 5);
+}
+
+#[tokio::test(start_paused = true)]
+async fn try_for_each_concurrent_cancels_in_flight_on_error_from_f() {
+    let finished = Cell::new(0);
+    let res = numbers(None)
+        .try_for_each_concurrent(None, async |i| {
+            if i == 0 {
+                return Err("f failed".to_string());
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            finished.set(finished.get() + 1);
+            Ok(())
+        })
+        .await;
+    assert_eq!(res, Err("f failed".to_string()));
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(finished.get(), 0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn try_for_each_concurrent_cancels_in_flight_on_error_item() {
+    let finished = Cell::new(0);
+    let res = numbers(Some(2))
+        .try_for_each_concurrent(None, async |_| {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            finished.set(finished.get() + 1);
+            Ok(())
+        })
+        .await;
+    assert_eq!(res, Err("stream failed at 2".to_string()));
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(finished.get(), 0);
+}
@@ -0,0 +1,40 @@
+use std::pin::pin;
+
+use make_async_stream::make_try_stream;
+use make_async_stream::AsyncStreamExt;
+
+#[tokio::test]
+async fn try_next_stops_at_error() {
+    let stream = make_try_stream(async move |tx| {
+        for i in 0..3 {
+            tx.send(i).await;
+        }
+        Err("broken".to_string())
+    });
+    let mut stream = pin!(stream);
+
+    let mut items = vec![];
+    let err = loop {
+        match stream.try_next().await {
+            Ok(Some(item)) => items.push(item),
+            Ok(None) => panic!("stream ended without an error"),
+            Err(err) => break err,
+        }
+    };
+
+    assert_eq!(items, vec![0, 1, 2]);
+    assert_eq!(err, "broken");
+    assert_eq!(stream.try_next().await, Ok(None));
+}
+
+#[tokio::test]
+async fn try_next_reports_end() {
+    let stream = make_try_stream(async move |tx| {
+        tx.send(1).await;
+        Ok::<(), String>(())
+    });
+    let mut stream = pin!(stream);
+
+    assert_eq!(stream.try_next().await, Ok(Some(1)));
+    assert_eq!(stream.try_next().await, Ok(None));
+}
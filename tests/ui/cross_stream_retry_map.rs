@@ -0,0 +1,16 @@
+use futures::stream;
+use futures::StreamExt;
+use make_async_stream::make_stream;
+use make_async_stream::retry_map;
+use make_async_stream::Sender;
+
+fn main() {
+    let _outer = make_stream(async |tx: &mut Sender<u32>| {
+        let _results: Vec<Result<u32, ()>> = retry_map(stream::iter([1u32]), 0, async |x| {
+            tx.send(*x).await;
+            Ok(*x)
+        })
+        .collect()
+        .await;
+    });
+}
@@ -0,0 +1,13 @@
+use futures::StreamExt;
+use make_async_stream::make_stream;
+use make_async_stream::Sender;
+
+fn main() {
+    let _outer = make_stream(async |outer: &mut Sender<u32>| {
+        let inner = make_stream(async |inner: &mut Sender<u32>| {
+            outer.send(1).await;
+            inner.send(2).await;
+        });
+        inner.collect::<Vec<u32>>().await;
+    });
+}
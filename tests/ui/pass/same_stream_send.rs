@@ -0,0 +1,19 @@
+use futures::StreamExt;
+use make_async_stream::make_stream;
+use make_async_stream::Sender;
+
+async fn send_pair(tx: &mut Sender<u32>, first: u32) {
+    tx.send(first).await;
+    tx.send(first + 1).await;
+}
+
+fn main() {
+    let items: Vec<u32> = futures::executor::block_on(
+        make_stream(async |tx: &mut Sender<u32>| {
+            send_pair(tx, 1).await;
+            send_pair(tx, 3).await;
+        })
+        .collect(),
+    );
+    assert_eq!(items, vec![1, 2, 3, 4]);
+}
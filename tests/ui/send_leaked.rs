@@ -0,0 +1,17 @@
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use make_async_stream::make_stream;
+use make_async_stream::Sender;
+
+type Leaked = Rc<RefCell<Option<Pin<Box<dyn Future<Output = ()>>>>>>;
+
+fn main() {
+    let leaked: Leaked = Rc::new(RefCell::new(None));
+    let slot = leaked.clone();
+    let _stream = make_stream(async move |tx: &mut Sender<u8>| {
+        *slot.borrow_mut() = Some(Box::pin(tx.send(1)));
+    });
+}
@@ -0,0 +1,7 @@
+use make_async_stream::make_stream;
+
+fn main() {
+    let _stream = make_stream(async |tx| {
+        tokio::task::spawn_local(tx.send(1));
+    });
+}
@@ -0,0 +1,9 @@
+use make_async_stream::make_stream;
+use make_async_stream::Sender;
+
+fn main() {
+    let mut escaped: Option<&mut Sender<u32>> = None;
+    let _stream = make_stream(async |tx: &mut Sender<u32>| {
+        escaped = Some(tx);
+    });
+}
@@ -0,0 +1,34 @@
+use std::cell::Cell;
+use std::pin::pin;
+use std::rc::Rc;
+use std::task::Context;
+use std::task::Poll;
+
+use futures::task::noop_waker_ref;
+use futures::Stream;
+use make_async_stream::make_watchdog_stream;
+
+#[test]
+fn watchdog_fires_once_for_a_stalled_generator() {
+    let stuck = Rc::new(Cell::new(0));
+    let on_stuck = {
+        let stuck = stuck.clone();
+        move || stuck.set(stuck.get() + 1)
+    };
+    let mut stream = pin!(make_watchdog_stream(3, on_stuck, async move |tx| {
+        tx.send(1).await;
+        std::future::pending::<()>().await;
+    }));
+    let mut cx = Context::from_waker(noop_waker_ref());
+
+    assert_eq!(stream.as_mut().poll_next(&mut cx), Poll::Ready(Some(1)));
+    for _ in 0..3 {
+        assert_eq!(stream.as_mut().poll_next(&mut cx), Poll::Pending);
+    }
+    assert_eq!(stuck.get(), 0);
+
+    for _ in 0..5 {
+        assert_eq!(stream.as_mut().poll_next(&mut cx), Poll::Pending);
+    }
+    assert_eq!(stuck.get(), 1);
+}
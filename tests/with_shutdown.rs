@@ -0,0 +1,82 @@
+use std::future;
+use std::pin::pin;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+
+use futures::channel::oneshot;
+use futures::task::noop_waker;
+use futures::StreamExt;
+use make_async_stream::make_stream;
+use make_async_stream::with_shutdown;
+
+#[tokio::test(start_paused = true)]
+async fn with_shutdown_stops_once_signal_resolves() {
+    let ticks = make_stream(async move |tx| {
+        for i in 0.. {
+            tx.send(i).await;
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    });
+
+    let signal = tokio::time::sleep(Duration::from_millis(15));
+    let items: Vec<u32> = with_shutdown(ticks, signal).collect().await;
+    assert_eq!(items, vec![0, 1]);
+}
+
+#[tokio::test]
+async fn with_shutdown_ends_with_stream() {
+    let numbers = make_stream(async move |tx| {
+        for i in 0..3 {
+            tx.send(i).await;
+        }
+    });
+
+    let items: Vec<u32> = with_shutdown(numbers, future::pending()).collect().await;
+    assert_eq!(items, vec![0, 1, 2]);
+}
+
+#[tokio::test]
+async fn with_shutdown_does_not_resume_generator_after_signal() {
+    let resumed = Arc::new(AtomicUsize::new(0));
+    let counter = resumed.clone();
+    let numbers = make_stream(async move |tx| {
+        for i in 0.. {
+            counter.fetch_add(1, Ordering::SeqCst);
+            tx.send(i).await;
+        }
+    });
+
+    let (fire, signal) = oneshot::channel::<()>();
+    let mut stream = pin!(with_shutdown(numbers, async move {
+        let _ = signal.await;
+    }));
+    assert_eq!(stream.next().await, Some(0));
+    assert_eq!(stream.next().await, Some(1));
+
+    let before = resumed.load(Ordering::SeqCst);
+    fire.send(()).unwrap();
+    assert_eq!(stream.next().await, None);
+    assert_eq!(resumed.load(Ordering::SeqCst), before);
+}
+
+#[tokio::test]
+async fn with_shutdown_delivers_buffered_item() {
+    let numbers = make_stream(async move |tx| {
+        tokio::task::yield_now().await;
+        tx.send(0).await;
+        future::pending::<()>().await;
+    });
+    let mut numbers = pin!(numbers);
+
+    let waker = noop_waker();
+    let mut cx = Context::from_waker(&waker);
+    assert_eq!(numbers.as_mut().poll_progress(&mut cx), Poll::Pending);
+    assert_eq!(numbers.as_mut().poll_progress(&mut cx), Poll::Ready(()));
+
+    let items: Vec<u32> = with_shutdown(numbers, future::ready(())).collect().await;
+    assert_eq!(items, vec![0]);
+}
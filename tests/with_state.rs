@@ -0,0 +1,28 @@
+use std::pin::pin;
+
+use futures::StreamExt;
+use make_async_stream::make_stream_with_state;
+
+#[derive(Debug, Default)]
+struct Progress {
+    produced: usize,
+}
+
+#[tokio::test]
+async fn caller_observes_generator_progress() {
+    let (stream, progress) =
+        make_stream_with_state(Progress::default(), async move |tx, progress| {
+            for i in 0..3 {
+                progress.borrow_mut().produced += 1;
+                tx.send(i).await;
+            }
+        });
+    let mut stream = pin!(stream);
+
+    assert_eq!(progress.borrow().produced, 0);
+    for i in 0..3 {
+        assert_eq!(stream.next().await, Some(i));
+        assert_eq!(progress.borrow().produced, i as usize + 1);
+    }
+    assert_eq!(stream.next().await, None);
+}
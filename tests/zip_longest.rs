@@ -0,0 +1,43 @@
+use futures::StreamExt;
+use make_async_stream::make_stream;
+use make_async_stream::zip_longest;
+
+#[tokio::test]
+async fn zip_longest_pads_the_shorter_generator() {
+    let pairs: Vec<(Option<u32>, Option<char>)> = zip_longest(
+        make_stream(async move |tx| {
+            for i in 0..2 {
+                tx.send(i).await;
+            }
+        }),
+        make_stream(async move |tx| {
+            for c in ['a', 'b', 'c', 'd'] {
+                tx.send(c).await;
+            }
+        }),
+    )
+    .collect()
+    .await;
+
+    assert_eq!(
+        pairs,
+        vec![
+            (Some(0), Some('a')),
+            (Some(1), Some('b')),
+            (None, Some('c')),
+            (None, Some('d')),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn zip_longest_ends_when_both_end_together() {
+    let pairs: Vec<(Option<u32>, Option<u32>)> = zip_longest(
+        make_stream(async move |tx| tx.send(1).await),
+        make_stream(async move |tx| tx.send(2).await),
+    )
+    .collect()
+    .await;
+
+    assert_eq!(pairs, vec![(Some(1), Some(2))]);
+}